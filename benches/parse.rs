@@ -0,0 +1,134 @@
+//! Compares parsing responses the way the client does, straight from the body using
+//! [`Parseable::parse()`], with going through a [`serde_json::Value`] first, which is what the
+//! client used to do.
+//!
+//! Run with `cargo bench --bench parse`. Besides the timings of criterion the allocations
+//! per parse are printed.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use helldivers2_rs::{
+    models::{raw::war::WarStatus, v1::planet::Planet},
+    prelude::Parseable,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// The amount of planets in the galaxy.
+const PLANETS: usize = 261;
+
+/// Counts the allocations of the benchmark.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Reads the recorded fixture at `tests/fixtures/{name}`.
+fn fixture(name: &str) -> Value {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap()
+}
+
+/// Repeats the entries of `list` until it holds a full galaxy of planets.
+fn fill_galaxy(list: &mut Value) {
+    let recorded = list.as_array().unwrap().clone();
+    *list = recorded.into_iter().cycle().take(PLANETS).collect();
+    for (index, planet) in list.as_array_mut().unwrap().iter_mut().enumerate() {
+        planet["index"] = index.into();
+    }
+}
+
+/// A `/api/v1/planets` response for the whole galaxy.
+fn planets() -> Vec<u8> {
+    let mut planets = fixture("v1/planets.json");
+    fill_galaxy(&mut planets);
+    serde_json::to_vec(&planets).unwrap()
+}
+
+/// A `/raw/api/WarSeason/{war_id}/Status` response for the whole galaxy.
+fn war_status() -> Vec<u8> {
+    let mut status = fixture("raw/war-status.json");
+    fill_galaxy(&mut status["planetStatus"]);
+    serde_json::to_vec(&status).unwrap()
+}
+
+fn from_value<T: DeserializeOwned>(body: &[u8]) -> T {
+    let json: Value = serde_json::from_slice(body).unwrap();
+    serde_json::from_value(json).unwrap()
+}
+
+fn parse<T: Parseable>(body: &[u8]) -> T {
+    T::parse(body).unwrap()
+}
+
+/// Prints the allocations of a single call to `parse`.
+fn report_allocations(name: &str, parse: impl Fn()) {
+    let (allocations, allocated) = (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED.load(Ordering::Relaxed),
+    );
+    parse();
+    println!(
+        "{name}: {} allocations, {} bytes",
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        ALLOCATED.load(Ordering::Relaxed) - allocated,
+    );
+}
+
+fn bench_planets(c: &mut Criterion) {
+    let body = planets();
+    report_allocations("planets/value", || {
+        black_box(from_value::<Vec<Planet>>(&body));
+    });
+    report_allocations("planets/parse", || {
+        black_box(parse::<Vec<Planet>>(&body));
+    });
+
+    let mut group = c.benchmark_group("planets");
+    group.bench_function("value", |b| {
+        b.iter(|| from_value::<Vec<Planet>>(black_box(&body)))
+    });
+    group.bench_function("parse", |b| {
+        b.iter(|| parse::<Vec<Planet>>(black_box(&body)))
+    });
+    group.finish();
+}
+
+fn bench_war_status(c: &mut Criterion) {
+    let body = war_status();
+    report_allocations("war_status/value", || {
+        black_box(from_value::<WarStatus>(&body));
+    });
+    report_allocations("war_status/parse", || {
+        black_box(parse::<WarStatus>(&body));
+    });
+
+    let mut group = c.benchmark_group("war_status");
+    group.bench_function("value", |b| {
+        b.iter(|| from_value::<WarStatus>(black_box(&body)))
+    });
+    group.bench_function("parse", |b| b.iter(|| parse::<WarStatus>(black_box(&body))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_planets, bench_war_status);
+criterion_main!(benches);
@@ -0,0 +1,317 @@
+//! An opt-in cache for API responses.
+//!
+//! The upstream data only refreshes every few seconds, so repeated requests for the same
+//! endpoint within a short time return the same data. A [`Cache`] keeps successful responses
+//! per endpoint for a configurable time to live (TTL) and serves them without sending a
+//! request or using up the rate limit. Enable it using
+//! [`HellApiBuilder::cache()`](crate::HellApiBuilder::cache).
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+use crate::transport::Response;
+
+/// A group of endpoints sharing the same TTL.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointFamily {
+    /// `/api/v1/war`
+    War,
+    /// `/api/v1/assignments` and `/raw/api/v2/Assignment/War/{war_id}`
+    Assignments,
+    /// `/api/v1/campaigns`
+    Campaigns,
+    /// `/api/v1/dispatches` and `/raw/api/NewsFeed/{war_id}`
+    Dispatches,
+    /// `/api/v1/steam`
+    Steam,
+    /// `/api/v1/planets` and `/api/v1/planet-events`
+    Planets,
+    /// `/api/v1/space-stations` and `/raw/api/v2/SpaceStation/War/{war_id}/{id}`
+    SpaceStations,
+    /// `/raw/api/WarSeason/current/WarID`
+    WarId,
+    /// `/raw/api/WarSeason/{war_id}/Status`
+    WarStatus,
+    /// `/raw/api/WarSeason/{war_id}/WarInfo`
+    WarInfo,
+    /// `/raw/api/Stats/war/{war_id}/summary`
+    WarSummary,
+    /// Any other endpoint.
+    Other,
+}
+
+impl EndpointFamily {
+    /// Returns the family the `endpoint` belongs to.
+    pub fn of(endpoint: &str) -> Self {
+        let path = endpoint.split('?').next().unwrap_or_default();
+        let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+
+        match segments.as_slice() {
+            ["api", "v1", "war", ..] => EndpointFamily::War,
+            ["api", "v1", "assignments", ..] => EndpointFamily::Assignments,
+            ["api", "v1", "campaigns", ..] => EndpointFamily::Campaigns,
+            ["api", "v1", "dispatches", ..] => EndpointFamily::Dispatches,
+            ["api", "v1", "steam", ..] => EndpointFamily::Steam,
+            ["api", "v1", "planets" | "planet-events", ..] => EndpointFamily::Planets,
+            ["api", "v1", "space-stations", ..] => EndpointFamily::SpaceStations,
+            ["raw", "api", "WarSeason", "current", "WarID"] => EndpointFamily::WarId,
+            ["raw", "api", "WarSeason", _, "Status"] => EndpointFamily::WarStatus,
+            ["raw", "api", "WarSeason", _, "WarInfo"] => EndpointFamily::WarInfo,
+            ["raw", "api", "Stats", "war", _, "summary"] => EndpointFamily::WarSummary,
+            ["raw", "api", "NewsFeed", ..] => EndpointFamily::Dispatches,
+            ["raw", "api", "v2", "Assignment", ..] => EndpointFamily::Assignments,
+            ["raw", "api", "v2", "SpaceStation", ..] => EndpointFamily::SpaceStations,
+            _ => EndpointFamily::Other,
+        }
+    }
+}
+
+/// Configures how long responses are cached.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheConfig {
+    /// The TTL of families without their own TTL.
+    default_ttl: Duration,
+    /// The TTL per endpoint family.
+    ttls: HashMap<EndpointFamily, Duration>,
+}
+
+impl CacheConfig {
+    /// Creates a configuration caching every endpoint for `default_ttl`.
+    pub fn new(default_ttl: Duration) -> Self {
+        CacheConfig {
+            default_ttl,
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// Sets the TTL of all endpoints of `family`. A TTL of zero disables caching for them.
+    pub fn with_ttl(mut self, family: EndpointFamily, ttl: Duration) -> Self {
+        self.ttls.insert(family, ttl);
+        self
+    }
+
+    /// Returns the TTL of the endpoints of `family`.
+    pub fn ttl(&self, family: EndpointFamily) -> Duration {
+        self.ttls.get(&family).copied().unwrap_or(self.default_ttl)
+    }
+}
+
+impl Default for CacheConfig {
+    /// Caches live war data for 10 seconds, news for a minute and data that only changes
+    /// with a new war for an hour.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(10))
+            .with_ttl(EndpointFamily::Steam, Duration::from_secs(60))
+            .with_ttl(EndpointFamily::Dispatches, Duration::from_secs(60))
+            .with_ttl(EndpointFamily::WarId, Duration::from_secs(3600))
+            .with_ttl(EndpointFamily::WarInfo, Duration::from_secs(3600))
+    }
+}
+
+/// Hit and miss counters of a [`Cache`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// How many requests were served from the cache.
+    pub hits: u64,
+    /// How many requests had to be sent to the API.
+    pub misses: u64,
+    /// How many responses are currently stored, including expired ones.
+    pub entries: usize,
+}
+
+/// A cached response.
+#[derive(Debug)]
+struct Entry {
+    response: Response,
+    expires_at: Instant,
+}
+
+/// Successful responses of a client keyed by endpoint.
+#[derive(Debug)]
+pub struct Cache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<String, Entry>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Cache {
+    /// Creates an empty cache using `config`.
+    pub fn new(config: CacheConfig) -> Self {
+        Cache {
+            config,
+            entries: Mutex::default(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// The configuration of this cache.
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    /// Returns the cached response of `endpoint` unless it has expired.
+    pub(crate) fn get(&self, endpoint: &str) -> Option<Response> {
+        let entries = self.entries.lock().unwrap();
+        let response = entries
+            .get(endpoint)
+            .filter(|entry| entry.expires_at > Instant::now())
+            .map(|entry| entry.response.clone());
+
+        let counter = match response {
+            Some(_) => &self.hits,
+            None => &self.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        response
+    }
+
+    /// Stores the `response` of `endpoint` for the TTL of its family.
+    pub(crate) fn insert(&self, endpoint: &str, response: Response) {
+        let ttl = self.config.ttl(EndpointFamily::of(endpoint));
+        if ttl.is_zero() {
+            return;
+        }
+
+        let entry = Entry {
+            response,
+            expires_at: Instant::now() + ttl,
+        };
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(endpoint.to_owned(), entry);
+    }
+
+    /// Removes the cached response of `endpoint`, e.g. `/api/v1/planets/127`.
+    pub fn invalidate(&self, endpoint: &str) {
+        self.entries.lock().unwrap().remove(endpoint);
+    }
+
+    /// Removes the cached responses of all endpoints of `family`.
+    pub fn invalidate_family(&self, family: EndpointFamily) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|endpoint, _| EndpointFamily::of(endpoint) != family);
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Returns the hit and miss counters of this cache.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.entries.lock().unwrap().len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::transport::Response;
+
+    use super::{Cache, CacheConfig, CacheStats, EndpointFamily};
+
+    #[test]
+    fn endpoint_families() {
+        assert_eq!(EndpointFamily::of("/api/v1/war"), EndpointFamily::War);
+        assert_eq!(
+            EndpointFamily::of("/api/v1/planets"),
+            EndpointFamily::Planets
+        );
+        assert_eq!(
+            EndpointFamily::of("/api/v1/planets/127"),
+            EndpointFamily::Planets
+        );
+        assert_eq!(
+            EndpointFamily::of("/api/v1/planet-events"),
+            EndpointFamily::Planets
+        );
+        assert_eq!(
+            EndpointFamily::of("/api/v1/steam/6098733004414627063"),
+            EndpointFamily::Steam
+        );
+        assert_eq!(
+            EndpointFamily::of("/raw/api/WarSeason/current/WarID"),
+            EndpointFamily::WarId
+        );
+        assert_eq!(
+            EndpointFamily::of("/raw/api/WarSeason/801/Status"),
+            EndpointFamily::WarStatus
+        );
+        assert_eq!(
+            EndpointFamily::of("/raw/api/NewsFeed/801"),
+            EndpointFamily::Dispatches
+        );
+        assert_eq!(
+            EndpointFamily::of("/raw/api/v2/SpaceStation/War/801/749875195"),
+            EndpointFamily::SpaceStations
+        );
+        assert_eq!(EndpointFamily::of("/api/v2/unknown"), EndpointFamily::Other);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn entries_expire_after_ttl() {
+        let cache = Cache::new(
+            CacheConfig::new(Duration::from_secs(10))
+                .with_ttl(EndpointFamily::Steam, Duration::from_secs(60))
+                .with_ttl(EndpointFamily::War, Duration::ZERO),
+        );
+        cache.insert("/api/v1/planets", Response::ok("[]"));
+        cache.insert("/api/v1/steam", Response::ok("[]"));
+        cache.insert("/api/v1/war", Response::ok("{}"));
+
+        assert!(cache.get("/api/v1/planets").is_some());
+        assert!(cache.get("/api/v1/war").is_none());
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(cache.get("/api/v1/planets").is_none());
+        assert!(cache.get("/api/v1/steam").is_some());
+
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 2,
+                entries: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn invalidation() {
+        let cache = Cache::new(CacheConfig::default());
+        cache.insert("/api/v1/planets", Response::ok("[]"));
+        cache.insert("/api/v1/planets/127", Response::ok("{}"));
+        cache.insert("/api/v1/campaigns", Response::ok("[]"));
+
+        cache.invalidate("/api/v1/campaigns");
+        assert_eq!(cache.stats().entries, 2);
+
+        cache.invalidate_family(EndpointFamily::Planets);
+        assert_eq!(cache.stats().entries, 0);
+
+        cache.insert("/api/v1/campaigns", Response::ok("[]"));
+        cache.clear();
+        assert!(cache.get("/api/v1/campaigns").is_none());
+    }
+}
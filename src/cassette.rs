@@ -0,0 +1,268 @@
+//! Record and replay HTTP interactions using cassette files.
+//!
+//! A [`RecordingTransport`] records every request path, status, headers and body it sees to a
+//! cassette file. A [`ReplayTransport`] serves the responses of such a file again, which makes
+//! it possible to capture a real war state once and reproduce it exactly later.
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    prelude::{Error, Result},
+    transport::{InMemoryTransport, Request, Response, Transport},
+};
+
+/// A single recorded request and the response it received.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The requested endpoint, e.g. `/api/v1/war`.
+    pub path: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The response headers as name/value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The response body.
+    pub body: String,
+}
+
+impl Interaction {
+    /// Records the `response` received for `path`.
+    pub fn new(path: &str, response: &Response) -> Self {
+        Interaction {
+            path: path.to_owned(),
+            status: response.status.as_u16(),
+            headers: response
+                .headers
+                .iter()
+                .map(|(name, value)| {
+                    let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+                    (name.to_string(), value)
+                })
+                .collect(),
+            body: String::from_utf8_lossy(&response.body).into_owned(),
+        }
+    }
+
+    /// Converts the recorded response back into a [`Response`].
+    pub fn response(&self) -> Result<Response> {
+        let status =
+            StatusCode::from_u16(self.status).map_err(|e| Error::TransportError(Box::new(e)))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name =
+                HeaderName::try_from(name).map_err(|e| Error::TransportError(Box::new(e)))?;
+            headers.append(name, HeaderValue::try_from(value)?);
+        }
+
+        Ok(Response {
+            status,
+            headers,
+            body: self.body.clone().into(),
+        })
+    }
+}
+
+/// A list of interactions in the order they happened.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// The recorded interactions.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Reads a cassette from the file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read(path)?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// Writes the cassette to the file at `path`.
+    ///
+    /// The cassette is written to a temporary file next to `path` first and then moved into
+    /// place, so an interrupted write never leaves a truncated cassette behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut temporary = OsString::from(path.as_os_str());
+        temporary.push(".tmp");
+
+        std::fs::write(&temporary, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&temporary, path)?;
+        Ok(())
+    }
+}
+
+/// A transport recording every interaction of the wrapped transport to a cassette file.
+///
+/// Interactions are kept in memory and written when the transport is dropped, e.g. together
+/// with the last clone of the client using it. Call [`save()`](RecordingTransport::save), or
+/// [`HellApi::save_cassette()`](crate::HellApi::save_cassette) for a client recording using
+/// [`record_cassette()`](crate::HellApiBuilder::record_cassette), to write them earlier, e.g.
+/// periodically while polling, so a recording survives the process being killed.
+#[derive(Debug)]
+pub struct RecordingTransport<T> {
+    inner: T,
+    path: PathBuf,
+    recording: Mutex<Recording>,
+}
+
+/// The interactions recorded so far.
+#[derive(Debug, Default)]
+struct Recording {
+    cassette: Cassette,
+    /// Whether interactions were recorded since the cassette was last saved.
+    unsaved: bool,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Records all interactions of `inner` to a new cassette at `path`.
+    pub fn new(inner: T, path: impl Into<PathBuf>) -> Self {
+        RecordingTransport {
+            inner,
+            path: path.into(),
+            recording: Mutex::default(),
+        }
+    }
+
+    /// Returns a copy of everything recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.recording.lock().unwrap().cassette.clone()
+    }
+}
+
+impl<T> RecordingTransport<T> {
+    /// Writes everything recorded so far to the cassette file.
+    pub fn save(&self) -> Result<()> {
+        let mut recording = self.recording.lock().unwrap();
+        recording.cassette.save(&self.path)?;
+        recording.unsaved = false;
+        Ok(())
+    }
+}
+
+impl<T> Drop for RecordingTransport<T> {
+    /// Saves interactions recorded since the last [`save()`](RecordingTransport::save).
+    /// Errors are ignored, call `save()` to handle them.
+    fn drop(&mut self) {
+        if self
+            .recording
+            .get_mut()
+            .is_ok_and(|recording| recording.unsaved)
+        {
+            let _ = self.save();
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Transport> Transport for RecordingTransport<T> {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let path = request.path.clone();
+        let response = self.inner.send(request).await?;
+
+        let mut recording = self.recording.lock().unwrap();
+        recording
+            .cassette
+            .interactions
+            .push(Interaction::new(&path, &response));
+        recording.unsaved = true;
+
+        Ok(response)
+    }
+}
+
+/// A transport replaying the responses of a cassette.
+///
+/// Interactions recorded for the same path are served in the order they were recorded while
+/// the last one is repeated for all further requests. Paths missing from the cassette are
+/// answered with `404 Not Found`.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    inner: InMemoryTransport,
+}
+
+impl ReplayTransport {
+    /// Replays the interactions of `cassette`.
+    pub fn new(cassette: &Cassette) -> Result<Self> {
+        let inner = InMemoryTransport::new();
+        for interaction in &cassette.interactions {
+            inner.push_response(interaction.path.clone(), interaction.response()?);
+        }
+
+        Ok(ReplayTransport { inner })
+    }
+
+    /// Replays the cassette stored at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(&Cassette::load(path)?)
+    }
+
+    /// Returns all requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.inner.requests()
+    }
+}
+
+#[async_trait]
+impl Transport for ReplayTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        self.inner.send(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{header::HeaderValue, StatusCode};
+
+    use crate::transport::{InMemoryTransport, Request, Response, Transport};
+
+    use super::{Cassette, Interaction, RecordingTransport};
+
+    #[test]
+    fn interaction_round_trip() {
+        let response = Response::new(StatusCode::TOO_MANY_REQUESTS, "slow down")
+            .with_header("Retry-After".parse().unwrap(), HeaderValue::from(3));
+        let interaction = Interaction::new("/api/v1/war", &response);
+
+        let json = serde_json::to_string(&Cassette {
+            interactions: vec![interaction.clone()],
+        })
+        .unwrap();
+        let cassette: Cassette = serde_json::from_str(&json).unwrap();
+        let replayed = cassette.interactions[0].response().unwrap();
+
+        assert_eq!(cassette.interactions[0], interaction);
+        assert_eq!(replayed.status, response.status);
+        assert_eq!(replayed.headers, response.headers);
+        assert_eq!(replayed.body, response.body);
+    }
+
+    #[tokio::test]
+    async fn recording_is_saved_on_drop() {
+        let path = std::env::temp_dir().join(format!("hd2-recording-{}.json", std::process::id()));
+        let inner = InMemoryTransport::new();
+        inner.push_response("/api/v1/war", Response::ok("{}"));
+        let transport = RecordingTransport::new(inner, &path);
+        let request = Request::new("http://localhost", "/api/v1/war", Default::default());
+
+        transport.send(request.clone()).await.unwrap();
+        assert!(!path.exists());
+        transport.save().unwrap();
+        assert_eq!(Cassette::load(&path).unwrap().interactions.len(), 1);
+
+        transport.send(request).await.unwrap();
+        drop(transport);
+        assert_eq!(Cassette::load(&path).unwrap().interactions.len(), 2);
+        std::fs::remove_file(path).unwrap();
+    }
+}
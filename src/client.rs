@@ -0,0 +1,373 @@
+//! The client used to query the Helldivers 2 community API.
+
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_LANGUAGE, USER_AGENT};
+
+use crate::{
+    cache::{Cache, CacheConfig},
+    cassette::{RecordingTransport, ReplayTransport},
+    coalesce::InFlight,
+    conditional::Validators,
+    middleware::{
+        self, RateLimit, RateLimitSnapshot, DEFAULT_RATE_LIMIT, DEFAULT_RATE_LIMIT_WINDOW,
+        DEFAULT_TIMEOUT,
+    },
+    prelude::Result,
+    retry::RetryPolicy,
+    transport::{ReqwestTransport, Transport},
+};
+
+/// helldivers2 API base url
+pub const DEFAULT_BASE_URL: &str = "https://api.helldivers2.dev";
+
+/// The `User-Agent` sent when none is configured.
+pub const DEFAULT_USER_AGENT: &str = concat!("helldivers2-rs/", env!("CARGO_PKG_VERSION"));
+
+/// The `X-Super-Client` sent when no client name is configured.
+pub const DEFAULT_CLIENT_NAME: &str = "helldivers2-rs";
+
+/// Header identifying the application that uses the API.
+pub const X_SUPER_CLIENT: HeaderName = HeaderName::from_static("x-super-client");
+
+/// Header containing contact information of the application developer.
+pub const X_SUPER_CONTACT: HeaderName = HeaderName::from_static("x-super-contact");
+
+/// Wrapper for the Helldivers 2 community driven API. Requests are blocking to ensure rate-limits.
+/// Use this struct to query for information.
+///
+/// Every instance carries its own configuration and rate-limit state. Clones share the
+/// underlying transport and rate-limit state.
+#[derive(Debug, Clone)]
+pub struct HellApi {
+    /// The transport performing the requests.
+    pub(crate) transport: Arc<dyn Transport>,
+    /// The base url every endpoint is appended to.
+    pub(crate) base_url: String,
+    /// The headers sent with every request.
+    pub(crate) headers: HeaderMap,
+    /// The rate-limit state of this client.
+    pub(crate) rate_limit: Arc<RateLimit>,
+    /// Decides how failed requests are retried.
+    pub(crate) retry: RetryPolicy,
+    /// How long a single request may take.
+    pub(crate) timeout: Duration,
+    /// The response cache, if enabled.
+    pub(crate) cache: Option<Arc<Cache>>,
+    /// The validators of earlier responses, if conditional requests are enabled.
+    pub(crate) validators: Option<Arc<Validators>>,
+    /// The requests currently in flight.
+    pub(crate) in_flight: Arc<InFlight>,
+    /// Whether parse errors contain the complete response body.
+    pub(crate) dump_payloads: bool,
+    /// The transport recording a cassette, if enabled.
+    pub(crate) recorder: Option<Arc<RecordingTransport<Arc<dyn Transport>>>>,
+}
+
+impl HellApi {
+    /// Creates a new client with the default configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying HTTP client cannot be initialized.
+    pub fn new() -> Self {
+        HellApiBuilder::default()
+            .build()
+            .expect("default configuration is valid")
+    }
+
+    /// Returns a builder to configure a new client.
+    pub fn builder() -> HellApiBuilder {
+        HellApiBuilder::default()
+    }
+
+    /// The base url this client sends its requests to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the current rate-limit state of this client.
+    pub fn rate_limit(&self) -> RateLimitSnapshot {
+        self.rate_limit.snapshot()
+    }
+
+    /// The response cache of this client. `None` unless enabled using
+    /// [`HellApiBuilder::cache()`].
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_deref()
+    }
+
+    /// Writes the interactions recorded so far to the cassette file. Does nothing unless
+    /// enabled using [`HellApiBuilder::record_cassette()`].
+    ///
+    /// Call this periodically while polling so a recording survives the process being killed.
+    pub fn save_cassette(&self) -> Result<()> {
+        match &self.recorder {
+            Some(recorder) => recorder.save(),
+            None => Ok(()),
+        }
+    }
+
+    /// Waits until a request can be sent without exceeding the rate limit.
+    ///
+    /// Use this together with the `try_` variants of the endpoints, e.g.
+    /// [`try_planets()`](HellApi::try_planets). A request may still fail with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) if other tasks
+    /// use up the rate limit first.
+    pub async fn wait_until_ready(&self) {
+        middleware::wait_until_ready(self).await;
+    }
+}
+
+impl Default for HellApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder to configure a [`HellApi`] client.
+///
+/// The community API asks every client to identify itself using the `X-Super-Client` and
+/// `X-Super-Contact` headers and may throttle or block clients that don't. Set them using
+/// [`client_name()`](HellApiBuilder::client_name) and [`contact()`](HellApiBuilder::contact).
+#[derive(Debug)]
+pub struct HellApiBuilder {
+    transport: Option<Arc<dyn Transport>>,
+    record_to: Option<PathBuf>,
+    replay_from: Option<PathBuf>,
+    base_url: String,
+    rate_limit: (u32, Duration),
+    retry: RetryPolicy,
+    timeout: Duration,
+    cache: Option<CacheConfig>,
+    conditional_requests: bool,
+    dump_payloads: bool,
+    headers: HeaderMap,
+    user_agent: String,
+    client_name: String,
+    contact: Option<String>,
+    language: Option<String>,
+}
+
+impl HellApiBuilder {
+    /// Sets the base url of the API, e.g. to point the client at a mirror.
+    /// Defaults to [`DEFAULT_BASE_URL`].
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Sets the amount of requests allowed per `window` until the API reports its own limit.
+    /// Defaults to [`DEFAULT_RATE_LIMIT`] requests per [`DEFAULT_RATE_LIMIT_WINDOW`].
+    pub fn rate_limit(mut self, limit: u32, window: Duration) -> Self {
+        self.rate_limit = (limit, window);
+        self
+    }
+
+    /// Sets how failed requests are retried. Defaults to [`RetryPolicy::default()`].
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets how long a single request may take until it fails with
+    /// [`Error::Timeout`](crate::prelude::Error::Timeout), including reading the body. Timed out
+    /// requests are retried like other transient failures. Defaults to [`DEFAULT_TIMEOUT`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Enables caching of successful responses. Disabled by default.
+    ///
+    /// See [`Cache`].
+    pub fn cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(config);
+        self
+    }
+
+    /// Sets whether requests are conditional. Enabled by default.
+    ///
+    /// If enabled, the `ETag` and `Last-Modified` headers of every response are sent back as
+    /// `If-None-Match` and `If-Modified-Since` with the next request to the same endpoint. If
+    /// the API answers with `304 Not Modified`, the previous value is returned without parsing.
+    pub fn conditional_requests(mut self, enabled: bool) -> Self {
+        self.conditional_requests = enabled;
+        self
+    }
+
+    /// Sets whether [`Error::ParseError`](crate::prelude::Error::ParseError) contains the
+    /// complete response body that failed to parse. Disabled by default as the payloads of
+    /// some endpoints are large.
+    pub fn dump_payloads(mut self, enabled: bool) -> Self {
+        self.dump_payloads = enabled;
+        self
+    }
+
+    /// Sets the `User-Agent` header. Defaults to [`DEFAULT_USER_AGENT`].
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Sets the `X-Super-Client` header identifying your application, e.g. `"my-discord-bot"`.
+    /// Defaults to [`DEFAULT_CLIENT_NAME`].
+    pub fn client_name(mut self, client_name: impl Into<String>) -> Self {
+        self.client_name = client_name.into();
+        self
+    }
+
+    /// Sets the `X-Super-Contact` header so the API maintainers can reach you, e.g. an email
+    /// address or Discord handle. Not sent unless configured.
+    pub fn contact(mut self, contact: impl Into<String>) -> Self {
+        self.contact = Some(contact.into());
+        self
+    }
+
+    /// Sets the `Accept-Language` header selecting the language of the texts returned by the
+    /// raw endpoints, e.g. `"de-DE"`. The API answers in English unless configured.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets the transport performing the requests. Defaults to a [`ReqwestTransport`].
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Records every interaction to a cassette file at `path`. The file is overwritten by
+    /// [`HellApi::save_cassette()`] and once the client and all its clones are dropped.
+    ///
+    /// See [`RecordingTransport`].
+    pub fn record_cassette(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_to = Some(path.into());
+        self
+    }
+
+    /// Serves all responses from the cassette file at `path` instead of the network. Takes
+    /// precedence over [`transport()`](HellApiBuilder::transport).
+    ///
+    /// See [`ReplayTransport`].
+    pub fn replay_cassette(mut self, path: impl Into<PathBuf>) -> Self {
+        self.replay_from = Some(path.into());
+        self
+    }
+
+    /// Adds a header that is sent with every request. Replaces any previous
+    /// value of the same header.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Adds all `headers` to the headers sent with every request.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// Fails if one of the identification headers is not a valid header value or the cassette
+    /// to replay cannot be read.
+    pub fn build(self) -> Result<HellApi> {
+        let mut headers = self.headers;
+        headers.insert(USER_AGENT, HeaderValue::try_from(self.user_agent)?);
+        headers.insert(X_SUPER_CLIENT, HeaderValue::try_from(self.client_name)?);
+        if let Some(contact) = self.contact {
+            headers.insert(X_SUPER_CONTACT, HeaderValue::try_from(contact)?);
+        }
+        if let Some(language) = self.language {
+            headers.insert(ACCEPT_LANGUAGE, HeaderValue::try_from(language)?);
+        }
+
+        let transport = match self.replay_from {
+            Some(path) => Arc::new(ReplayTransport::open(path)?),
+            None => self
+                .transport
+                .unwrap_or_else(|| Arc::new(ReqwestTransport::new())),
+        };
+        let recorder = self
+            .record_to
+            .map(|path| Arc::new(RecordingTransport::new(transport.clone(), path)));
+
+        Ok(HellApi {
+            transport: match &recorder {
+                Some(recorder) => recorder.clone(),
+                None => transport,
+            },
+            base_url: self.base_url,
+            headers,
+            rate_limit: Arc::new(RateLimit::new(self.rate_limit.0, self.rate_limit.1)),
+            retry: self.retry,
+            timeout: self.timeout,
+            cache: self.cache.map(|config| Arc::new(Cache::new(config))),
+            validators: self
+                .conditional_requests
+                .then(|| Arc::new(Validators::default())),
+            in_flight: Arc::default(),
+            dump_payloads: self.dump_payloads,
+            recorder,
+        })
+    }
+}
+
+impl Default for HellApiBuilder {
+    fn default() -> Self {
+        HellApiBuilder {
+            transport: None,
+            record_to: None,
+            replay_from: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            rate_limit: (DEFAULT_RATE_LIMIT, DEFAULT_RATE_LIMIT_WINDOW),
+            retry: RetryPolicy::default(),
+            timeout: DEFAULT_TIMEOUT,
+            cache: None,
+            conditional_requests: true,
+            dump_payloads: false,
+            headers: HeaderMap::new(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            client_name: DEFAULT_CLIENT_NAME.to_owned(),
+            contact: None,
+            language: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::prelude::Error;
+
+    use super::{HellApi, DEFAULT_BASE_URL};
+
+    #[test]
+    fn builder_configures_base_url() {
+        assert_eq!(HellApi::new().base_url(), DEFAULT_BASE_URL);
+
+        let api = HellApi::builder()
+            .base_url("http://localhost:8080/")
+            .build()
+            .unwrap();
+        assert_eq!(api.base_url(), "http://localhost:8080");
+    }
+
+    #[test]
+    fn clients_have_separate_rate_limits() {
+        let first = HellApi::new();
+        let second = HellApi::new();
+
+        assert!(!Arc::ptr_eq(&first.rate_limit, &second.rate_limit));
+        assert!(Arc::ptr_eq(&first.rate_limit, &first.clone().rate_limit));
+    }
+
+    #[test]
+    fn invalid_identification_is_rejected() {
+        let result = HellApi::builder().contact("line\nbreak").build();
+
+        assert!(matches!(result, Err(Error::InvalidHeader(_))));
+    }
+}
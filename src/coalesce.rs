@@ -0,0 +1,245 @@
+//! Coalescing of concurrent requests for the same endpoint.
+//!
+//! The first caller requesting an endpoint becomes the leader and sends the request. Callers
+//! requesting the same endpoint while the leader is still busy become followers and receive
+//! the leader's result instead of sending their own request.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::watch;
+
+use crate::prelude::{Error, Result};
+
+/// The outcome of a request shared with the followers.
+type Outcome = core::result::Result<Arc<dyn Any + Send + Sync>, Arc<Error>>;
+
+/// The requests currently in flight keyed by endpoint.
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    calls: Mutex<HashMap<String, watch::Receiver<Option<Outcome>>>>,
+}
+
+impl InFlight {
+    /// Sends the request for `endpoint` using `send` unless an identical request is already in
+    /// flight, in which case its result is returned.
+    ///
+    /// Followers receive errors as [`Error::Shared`] while the leader receives its own error
+    /// unchanged. Followers send their own request if the leader is cancelled.
+    pub(crate) async fn coalesce<T, F>(&self, endpoint: &str, send: impl Fn() -> F) -> Result<T>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: std::future::Future<Output = Result<T>>,
+    {
+        loop {
+            let mut receiver = match self.join(endpoint) {
+                Ok(leader) => return leader.lead(send()).await,
+                Err(receiver) => receiver,
+            };
+
+            // the leader was cancelled before it finished
+            let Ok(outcome) = receiver.wait_for(Option::is_some).await else {
+                continue;
+            };
+            match outcome.as_ref() {
+                Some(Ok(value)) => {
+                    if let Some(value) = value.downcast_ref::<T>() {
+                        return Ok(value.clone());
+                    }
+                }
+                Some(Err(error)) => return Err(Error::Shared(error.clone())),
+                None => {}
+            }
+        }
+    }
+
+    /// Makes the caller the leader for `endpoint` or returns the receiver of the leader's
+    /// outcome.
+    fn join(
+        &self,
+        endpoint: &str,
+    ) -> core::result::Result<Leader<'_>, watch::Receiver<Option<Outcome>>> {
+        let mut calls = self.calls.lock().unwrap();
+        if let Some(receiver) = calls.get(endpoint) {
+            return Err(receiver.clone());
+        }
+
+        let (sender, receiver) = watch::channel(None);
+        calls.insert(endpoint.to_owned(), receiver);
+        Ok(Leader {
+            in_flight: self,
+            endpoint: Some(endpoint.to_owned()),
+            sender,
+        })
+    }
+}
+
+/// The caller sending the request for an endpoint. Removes the endpoint from the requests in
+/// flight when dropped.
+struct Leader<'a> {
+    in_flight: &'a InFlight,
+    endpoint: Option<String>,
+    sender: watch::Sender<Option<Outcome>>,
+}
+
+impl Leader<'_> {
+    /// Awaits the `request` and shares its result with all followers.
+    async fn lead<T>(mut self, request: impl std::future::Future<Output = Result<T>>) -> Result<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let result = request.await;
+        // nobody can join once the endpoint has been removed
+        self.leave();
+        if self.sender.receiver_count() == 0 {
+            return result;
+        }
+
+        match result {
+            Ok(value) => {
+                self.sender.send_replace(Some(Ok(Arc::new(value.clone()))));
+                Ok(value)
+            }
+            Err(error) => {
+                let shared = Arc::new(error.duplicate());
+                self.sender.send_replace(Some(Err(shared)));
+                Err(error)
+            }
+        }
+    }
+
+    /// Removes the endpoint from the requests in flight unless that already happened.
+    fn leave(&mut self) {
+        if let Some(endpoint) = self.endpoint.take() {
+            self.in_flight.calls.lock().unwrap().remove(&endpoint);
+        }
+    }
+}
+
+impl Drop for Leader<'_> {
+    fn drop(&mut self) {
+        self.leave();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use crate::prelude::{Error, Result};
+
+    use super::InFlight;
+
+    /// Calls `coalesce()` for `/api/v1/war` with a request taking a second to complete.
+    async fn call(
+        in_flight: &InFlight,
+        sent: &AtomicU32,
+        result: fn() -> Result<i32>,
+    ) -> Result<i32> {
+        in_flight
+            .coalesce("/api/v1/war", || async {
+                sent.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                result()
+            })
+            .await
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_calls_share_one_request() {
+        let in_flight = InFlight::default();
+        let sent = AtomicU32::new(0);
+
+        let results = tokio::join!(
+            call(&in_flight, &sent, || Ok(801)),
+            call(&in_flight, &sent, || Ok(802)),
+            call(&in_flight, &sent, || Ok(803)),
+        );
+
+        assert_eq!(results.0.unwrap(), 801);
+        assert_eq!(results.1.unwrap(), 801);
+        assert_eq!(results.2.unwrap(), 801);
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+
+        call(&in_flight, &sent, || Ok(804)).await.unwrap();
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn errors_are_shared() {
+        let in_flight = InFlight::default();
+        let sent = AtomicU32::new(0);
+        let not_found = || {
+            Err(Error::NotFound {
+                endpoint: String::from("/api/v1/war"),
+            })
+        };
+
+        let (leader, follower) = tokio::join!(
+            call(&in_flight, &sent, not_found),
+            call(&in_flight, &sent, not_found),
+        );
+
+        assert!(matches!(leader, Err(Error::NotFound { .. })));
+        let error = follower.unwrap_err();
+        assert!(
+            matches!(&error, Error::Shared(error) if matches!(**error, Error::NotFound { .. }))
+        );
+        assert!(matches!(error.root(), Error::NotFound { .. }));
+        assert_eq!(error.endpoint(), Some("/api/v1/war"));
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+
+        let result = call(&in_flight, &sent, not_found).await;
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shared_errors_stay_retryable() {
+        let in_flight = InFlight::default();
+        let sent = AtomicU32::new(0);
+        let reset = || Err(io::Error::from(io::ErrorKind::ConnectionReset).into());
+
+        let (leader, follower) = tokio::join!(
+            call(&in_flight, &sent, reset),
+            call(&in_flight, &sent, reset),
+        );
+
+        assert!(matches!(leader, Err(Error::IoError(_))));
+        let error = follower.unwrap_err();
+        assert!(
+            matches!(error.root(), Error::IoError(e) if e.kind() == io::ErrorKind::ConnectionReset)
+        );
+        assert!(error.is_retryable());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn followers_take_over_from_cancelled_leader() {
+        let in_flight = Arc::new(InFlight::default());
+        let sent = Arc::new(AtomicU32::new(0));
+
+        let leader = tokio::spawn({
+            let (in_flight, sent) = (in_flight.clone(), sent.clone());
+            async move { call(&in_flight, &sent, || Ok(801)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let follower = tokio::spawn({
+            let (in_flight, sent) = (in_flight.clone(), sent.clone());
+            async move { call(&in_flight, &sent, || Ok(802)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        leader.abort();
+
+        assert_eq!(follower.await.unwrap().unwrap(), 802);
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+    }
+}
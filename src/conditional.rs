@@ -0,0 +1,115 @@
+//! Conditional requests using the `ETag` and `Last-Modified` validators of earlier responses.
+//!
+//! When a response carries validators, its parsed value is kept together with them. Later
+//! requests for the same endpoint send `If-None-Match` and `If-Modified-Since` and a
+//! `304 Not Modified` answer returns the kept value without parsing anything.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+
+use crate::transport::Response;
+
+/// What is needed to revalidate the last successful response of an endpoint.
+#[derive(Debug)]
+pub(crate) struct Revalidation<T> {
+    /// The conditional headers to send.
+    pub(crate) headers: HeaderMap,
+    /// The response the validators belong to.
+    pub(crate) response: Response,
+    /// The parsed body of `response`.
+    pub(crate) value: T,
+}
+
+/// The last successful response of an endpoint and its validators.
+#[derive(Debug)]
+struct Validated {
+    headers: HeaderMap,
+    response: Response,
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+/// Validators of the last successful response per endpoint.
+#[derive(Debug, Default)]
+pub(crate) struct Validators {
+    entries: Mutex<HashMap<String, Validated>>,
+}
+
+impl Validators {
+    /// Returns the data to revalidate the last response of `endpoint`, if it had validators.
+    pub(crate) fn get<T: Clone + 'static>(&self, endpoint: &str) -> Option<Revalidation<T>> {
+        let entries = self.entries.lock().unwrap();
+        let validated = entries.get(endpoint)?;
+
+        Some(Revalidation {
+            headers: validated.headers.clone(),
+            response: validated.response.clone(),
+            value: validated.value.downcast_ref::<T>()?.clone(),
+        })
+    }
+
+    /// Keeps the validators of `response` and its parsed `value` for `endpoint`. Forgets the
+    /// endpoint if the response has no validators.
+    pub(crate) fn insert<T>(&self, endpoint: &str, response: &Response, value: &T)
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let mut headers = HeaderMap::new();
+        if let Some(etag) = response.headers.get(ETAG) {
+            headers.insert(IF_NONE_MATCH, etag.clone());
+        }
+        if let Some(last_modified) = response.headers.get(LAST_MODIFIED) {
+            headers.insert(IF_MODIFIED_SINCE, last_modified.clone());
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        if headers.is_empty() {
+            entries.remove(endpoint);
+            return;
+        }
+
+        let validated = Validated {
+            headers,
+            response: response.clone(),
+            value: Arc::new(value.clone()),
+        };
+        entries.insert(endpoint.to_owned(), validated);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+
+    use crate::transport::Response;
+
+    use super::Validators;
+
+    #[test]
+    fn validators_become_conditional_headers() {
+        let validators = Validators::default();
+        let response = Response::ok("801")
+            .with_header(ETAG, HeaderValue::from_static("\"v1\""))
+            .with_header(
+                LAST_MODIFIED,
+                HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+            );
+        validators.insert("/api/v1/war", &response, &801);
+
+        let revalidation = validators.get::<i32>("/api/v1/war").unwrap();
+        assert_eq!(revalidation.value, 801);
+        assert_eq!(revalidation.headers[IF_NONE_MATCH], "\"v1\"");
+        assert_eq!(
+            revalidation.headers[IF_MODIFIED_SINCE],
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+        );
+        assert!(validators.get::<String>("/api/v1/war").is_none());
+
+        validators.insert("/api/v1/war", &Response::ok("802"), &802);
+        assert!(validators.get::<i32>("/api/v1/war").is_none());
+    }
+}
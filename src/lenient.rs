@@ -0,0 +1,207 @@
+//! Lenient parsing tolerating changes of the API schemas.
+//!
+//! ArrowHead changes the raw schemas frequently. By default a single unexpected value fails
+//! the whole response with [`Error::ParseError`](crate::prelude::Error::ParseError). Requests
+//! wrapped in [`lenient()`] instead skip list elements that fail to parse and report them as
+//! [`DriftWarning`]s. With the `extra` feature, fields unknown to this crate are kept in the
+//! `extra` map of each model.
+//!
+//! ```no_run
+//! # async fn example() -> helldivers2_rs::prelude::Result<()> {
+//! use helldivers2_rs::{lenient::lenient, HellApi};
+//!
+//! let api = HellApi::new();
+//! let planets = lenient(api.planets()).await?;
+//! for warning in &planets.warnings {
+//!     eprintln!("{warning}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{cell::RefCell, fmt, future::Future};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::{Path, Segment};
+
+use crate::prelude::Result;
+
+tokio::task_local! {
+    /// The warnings of the lenient request currently running on this task.
+    static WARNINGS: RefCell<Vec<DriftWarning>>;
+}
+
+/// A value parsed leniently together with everything that was skipped.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lenient<T> {
+    /// The parsed value.
+    pub value: T,
+    /// The list elements that failed to parse and were skipped.
+    pub warnings: Vec<DriftWarning>,
+}
+
+/// A list element of a response that failed to parse and was skipped.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriftWarning {
+    /// The requested endpoint.
+    pub endpoint: String,
+    /// The path of the offending value, e.g. `planetEvents[3].campaignId`.
+    pub path: String,
+    /// Why the value failed to parse.
+    pub message: String,
+}
+
+impl fmt::Display for DriftWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Skipped invalid value at `{}` of {}. {}",
+            self.path, self.endpoint, self.message
+        )
+    }
+}
+
+/// Parses the responses of `request` leniently and returns the result with all warnings.
+///
+/// Requests inside `request` are neither coalesced with concurrent requests nor conditional,
+/// so every call reports its own warnings. Their responses are not cached or revalidated
+/// later either, so a later strict request never returns a leniently parsed value. Errors
+/// other than list elements failing to parse are returned as usual.
+pub async fn lenient<T>(request: impl Future<Output = Result<T>>) -> Result<Lenient<T>> {
+    WARNINGS
+        .scope(RefCell::default(), async move {
+            let value = request.await?;
+            let warnings = WARNINGS.with(RefCell::take);
+            Ok(Lenient { value, warnings })
+        })
+        .await
+}
+
+/// Returns whether the current request is lenient.
+pub(crate) fn is_active() -> bool {
+    WARNINGS.try_with(|_| ()).is_ok()
+}
+
+/// Parses the `json` response of `endpoint` removing every list element that fails to parse.
+/// Errors outside of lists are returned.
+pub(crate) fn parse<T: DeserializeOwned>(endpoint: &str, json: &[u8]) -> Result<T> {
+    let mut json: Value = serde_json::from_slice(json)?;
+    loop {
+        let error = match serde_path_to_error::deserialize(&json) {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        if !remove_element(&mut json, error.path()) {
+            return Err(error.into());
+        }
+
+        let warning = DriftWarning {
+            endpoint: endpoint.to_owned(),
+            path: error.path().to_string(),
+            message: error.inner().to_string(),
+        };
+        let _ = WARNINGS.try_with(|warnings| warnings.borrow_mut().push(warning));
+    }
+}
+
+/// Removes the innermost list element containing the value at `path` from `json`. Returns
+/// `false` if there is none.
+fn remove_element(json: &mut Value, path: &Path) -> bool {
+    let segments: Vec<_> = path.iter().collect();
+    let Some(last) = segments
+        .iter()
+        .rposition(|segment| matches!(segment, Segment::Seq { .. }))
+    else {
+        return false;
+    };
+
+    let mut list = json;
+    for segment in &segments[..last] {
+        let next = match segment {
+            Segment::Seq { index } => list.get_mut(index),
+            Segment::Map { key } => list.get_mut(key),
+            _ => None,
+        };
+        match next {
+            Some(next) => list = next,
+            None => return false,
+        }
+    }
+
+    match (list.as_array_mut(), segments[last]) {
+        (Some(list), Segment::Seq { index }) if *index < list.len() => {
+            list.remove(*index);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::prelude::Error;
+
+    use super::{lenient, DriftWarning};
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Event {
+        id: i32,
+        tags: Vec<u8>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Status {
+        time: u64,
+        events: Vec<Event>,
+    }
+
+    fn parse<T: serde::de::DeserializeOwned>(json: &str) -> crate::prelude::Result<T> {
+        super::parse("/test", json.as_bytes())
+    }
+
+    #[tokio::test]
+    async fn invalid_list_elements_are_skipped() {
+        let json = r#"{
+            "time": 1,
+            "events": [
+                {"id": 1, "tags": [1]},
+                {"id": null, "tags": []},
+                {"id": 3, "tags": [2, -1]},
+                {"id": 4, "tags": []}
+            ]
+        }"#;
+
+        let status = lenient(async { parse::<Status>(json) }).await.unwrap();
+
+        let ids: Vec<_> = status.value.events.iter().map(|e| e.id).collect();
+        assert_eq!(ids, [1, 3, 4]);
+        assert_eq!(status.value.events[1].tags, [2]);
+        assert_eq!(
+            status.warnings[0],
+            DriftWarning {
+                endpoint: String::from("/test"),
+                path: String::from("events[1].id"),
+                message: String::from("invalid type: null, expected i32"),
+            }
+        );
+        assert_eq!(status.warnings[1].path, "events[1].tags[1]");
+        assert_eq!(status.warnings.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn errors_outside_lists_fail() {
+        let json = r#"{"time": null, "events": []}"#;
+
+        let result = lenient(async { parse::<Status>(json) }).await;
+
+        match result {
+            Err(Error::ParseError { path, .. }) => assert_eq!(path, "time"),
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+}
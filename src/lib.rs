@@ -1,9 +1,15 @@
 #![allow(dead_code)]
 
+pub mod cache;
+pub mod cassette;
+pub mod client;
+mod coalesce;
+mod conditional;
+pub mod lenient;
 pub mod middleware;
 pub mod models;
 pub mod prelude;
+pub mod retry;
+pub mod transport;
 
-/// Wrapper for the Helldivers 2 community driven API. This wrapper is blocking to ensure rate-limits.
-/// Use this struct to query for information.
-pub struct HellApi;
+pub use client::{HellApi, HellApiBuilder};
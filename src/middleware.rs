@@ -2,112 +2,887 @@
 //! the fair use limitations of the HellHub API.
 
 use std::{
-    sync::atomic::{AtomicU32, AtomicU64, Ordering},
-    time::Duration,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use reqwest::header::HeaderMap;
+use reqwest::{header::HeaderMap, StatusCode};
+use tokio::time::Instant;
 
-use crate::prelude::{Error, Parseable, Result, RATE_LIMIT};
+use crate::{
+    conditional::Revalidation,
+    lenient,
+    prelude::{Error, Parseable, Result},
+    transport::{Request, Response},
+    HellApi,
+};
+
+/// The amount of requests allowed per [`DEFAULT_RATE_LIMIT_WINDOW`] unless the API reports
+/// otherwise.
+pub const DEFAULT_RATE_LIMIT: u32 = 5;
+
+/// The window in which [`DEFAULT_RATE_LIMIT`] requests are allowed.
+pub const DEFAULT_RATE_LIMIT_WINDOW: Duration = Duration::from_secs(10);
+
+/// How long a request may take until it fails with [`Error::Timeout`] unless configured
+/// otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Values of `X-Ratelimit-Reset` above this are unix timestamps, all others are seconds
+/// until the reset.
+const UNIX_TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+/// A point-in-time view of the rate-limit state of a client.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitSnapshot {
+    /// The amount of requests allowed per window.
+    pub limit: u32,
+    /// How many requests can be sent right now.
+    pub remaining: u32,
+    /// When all requests of the window will be available again. `None` if they already are.
+    pub reset: Option<std::time::Instant>,
+}
 
-/// helldivers2 API base url
-const BASE_URL: &str = "https://api.helldivers2.dev";
+/// Token bucket holding the permits of a client.
+#[derive(Debug)]
+struct Bucket {
+    /// Defines the request limit per window.
+    limit: u32,
+    /// The window in which `limit` requests are allowed.
+    window: Duration,
+    /// How many requests are still remaining. Refills continuously over the window.
+    tokens: f64,
+    /// The last time `tokens` was refilled.
+    refilled: Instant,
+    /// No requests are allowed before this instant, e.g. after receiving a HTTP429.
+    blocked_until: Option<Instant>,
+}
+
+impl Bucket {
+    /// The amount of tokens regained per second.
+    fn rate(&self) -> f64 {
+        f64::from(self.limit) / self.window.as_secs_f64()
+    }
+
+    /// Adds the tokens regained since the last refill.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate()).min(f64::from(self.limit));
+        self.refilled = now;
+        if self.blocked_until.is_some_and(|until| until <= now) {
+            self.blocked_until = None;
+        }
+    }
 
+    /// Takes a token if one is available. Otherwise returns the amount of time to wait until
+    /// one will be.
+    fn try_take(&mut self, now: Instant) -> core::result::Result<(), Duration> {
+        self.refill(now);
+        if let Some(until) = self.blocked_until {
+            return Err(until - now);
+        }
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.rate()))
+        }
+    }
+}
+
+/// Rate-limit state of a single [`HellApi`] client.
+///
+/// Requests are limited by a token bucket which starts out full and refills over the
+/// window. The bucket is corrected using the `X-Ratelimit-Limit`, `X-Ratelimit-Remaining`,
+/// `X-Ratelimit-Reset` and `Retry-After` headers of every response. Waiting requests are
+/// served in the order they arrived.
+#[derive(Debug)]
 pub(crate) struct RateLimit {
-    /// Defines the request limit.
-    x_limit: AtomicU32,
-    /// How many requests are still remaining.
-    x_remaining: AtomicU32,
-    /// Retry delay in seconds when received a HTTP429. The value `0` will be treated as a None value.
-    retry_after: AtomicU64,
+    bucket: Mutex<Bucket>,
+    /// Queue of requests waiting for a permit.
+    queue: tokio::sync::Mutex<()>,
 }
 
 impl RateLimit {
-    /// Updates the rate-limit information using the latest response headers.
+    /// Creates a rate limit allowing `limit` requests per `window`.
+    pub(crate) fn new(limit: u32, window: Duration) -> Self {
+        let limit = limit.max(1);
+        RateLimit {
+            bucket: Mutex::new(Bucket {
+                limit,
+                window: window.max(Duration::from_millis(1)),
+                tokens: f64::from(limit),
+                refilled: Instant::now(),
+                blocked_until: None,
+            }),
+            queue: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Updates the rate-limit information using the latest response headers. Missing headers
+    /// leave the state untouched.
     fn update(&self, headers: &HeaderMap) {
-        let limit = headers
-            .get("X-Ratelimit-Limit")
-            .map(|v| v.to_str().unwrap_or("0").parse::<u32>().unwrap_or(0u32))
-            .unwrap_or(0);
-        let remaining = headers
-            .get("X-Ratelimit-Remaining")
-            .map(|v| v.to_str().unwrap_or("0").parse::<u32>().unwrap_or(0u32))
-            .unwrap_or(0);
-        let retry_after = headers
-            .get("Retry-After")
-            .map(|v| v.to_str().unwrap_or("0").parse::<u64>().unwrap_or(1u64));
-
-        self.x_limit.store(limit, Ordering::Relaxed);
-        self.x_remaining.store(remaining, Ordering::Relaxed);
-        self.retry_after
-            .store(retry_after.unwrap_or(0), Ordering::Relaxed);
-    }
-
-    /// Returns whether a request is allowed.
-    fn allowed(&self) -> bool {
-        self.x_remaining.load(Ordering::Relaxed) > 0 && self.retry_after.load(Ordering::Relaxed) < 1
-    }
-
-    /// Returns the `Ok` variant if a request is allowed. Otherwise returns the amount of time to wait
-    /// until retrying.
-    fn try_wait(&self) -> core::result::Result<(), Duration> {
-        if self.allowed() {
-            Ok(())
-        } else {
-            let retry = self.retry_after.load(Ordering::Relaxed);
-            Err(Duration::from_secs(match retry {
-                0 => 1,
-                v => v + 1,
-            }))
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        let now = Instant::now();
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(now);
+
+        if let Some(limit) = header("X-Ratelimit-Limit").filter(|&limit| limit > 0) {
+            bucket.limit = u32::try_from(limit).unwrap_or(u32::MAX);
+        }
+        if let Some(remaining) = header("X-Ratelimit-Remaining") {
+            // requests sent concurrently have already been deducted locally
+            bucket.tokens = bucket.tokens.min(remaining as f64);
+            if remaining == 0 {
+                if let Some(reset) = header("X-Ratelimit-Reset") {
+                    let until = now + reset_in(reset);
+                    bucket.blocked_until = bucket.blocked_until.max(Some(until));
+                }
+            }
+        }
+        if let Some(retry_after) = header("Retry-After") {
+            bucket.tokens = 0.0;
+            let until = now + Duration::from_secs(retry_after);
+            bucket.blocked_until = bucket.blocked_until.max(Some(until));
+        }
+    }
+
+    /// Takes a permit if one is available right now. Otherwise returns the amount of time to
+    /// wait until retrying.
+    fn try_acquire(&self) -> core::result::Result<(), Duration> {
+        let now = Instant::now();
+        match self.queue.try_lock() {
+            Ok(_turn) => self.bucket.lock().unwrap().try_take(now),
+            // others are already waiting for a permit
+            Err(_) => Err(self.next_permit_in(now)),
+        }
+    }
+
+    /// Waits until a permit is available and takes it.
+    async fn acquire(&self) {
+        let _turn = self.queue.lock().await;
+        loop {
+            let now = Instant::now();
+            let wait_for = match self.bucket.lock().unwrap().try_take(now) {
+                Ok(()) => return,
+                Err(wait_for) => wait_for,
+            };
+            tokio::time::sleep(wait_for).await;
         }
     }
+
+    /// Waits until a permit is available without taking it.
+    async fn ready(&self) {
+        let _turn = self.queue.lock().await;
+        loop {
+            let wait_for = self.next_permit_in(Instant::now());
+            if wait_for.is_zero() {
+                return;
+            }
+            tokio::time::sleep(wait_for).await;
+        }
+    }
+
+    /// Returns how long it takes until the next permit is available.
+    fn next_permit_in(&self, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(now);
+        let blocked = bucket
+            .blocked_until
+            .map_or(Duration::ZERO, |until| until - now);
+        let refill = Duration::from_secs_f64((1.0 - bucket.tokens).max(0.0) / bucket.rate());
+        blocked.max(refill)
+    }
+
+    /// Returns the current rate-limit state.
+    pub(crate) fn snapshot(&self) -> RateLimitSnapshot {
+        let now = Instant::now();
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(now);
+
+        let missing = f64::from(bucket.limit) - bucket.tokens;
+        let full_in = Duration::from_secs_f64(missing.max(0.0) / bucket.rate());
+        let reset = match bucket.blocked_until {
+            Some(until) => Some(until + bucket.window),
+            None if missing > 0.0 => Some(now + full_in),
+            None => None,
+        };
+
+        RateLimitSnapshot {
+            limit: bucket.limit,
+            remaining: bucket.tokens as u32,
+            reset: reset.map(Instant::into_std),
+        }
+    }
+}
+
+/// Converts the value of a `X-Ratelimit-Reset` header into the time until the reset.
+fn reset_in(reset: u64) -> Duration {
+    if reset > UNIX_TIMESTAMP_THRESHOLD {
+        let reset = UNIX_EPOCH + Duration::from_secs(reset);
+        reset.duration_since(SystemTime::now()).unwrap_or_default()
+    } else {
+        Duration::from_secs(reset)
+    }
 }
 
 impl Default for RateLimit {
     fn default() -> Self {
-        RateLimit {
-            x_limit: AtomicU32::new(5),
-            x_remaining: AtomicU32::new(0),
-            retry_after: AtomicU64::new(0),
+        Self::new(DEFAULT_RATE_LIMIT, DEFAULT_RATE_LIMIT_WINDOW)
+    }
+}
+
+/// The maximum length of the body excerpt in [`Error::HttpStatus`].
+const BODY_SNIPPET_LENGTH: usize = 200;
+
+/// Sends a GET request for `endpoint` using the configuration of `api` and the additional
+/// `conditions`. Fails with [`Error::Timeout`] if no response arrives in time.
+async fn get(api: &HellApi, endpoint: &str, conditions: &HeaderMap) -> Result<Response> {
+    let mut headers = api.headers.clone();
+    headers.extend(conditions.clone());
+    let request = Request::new(&api.base_url, endpoint, headers);
+    let timeout = || Error::Timeout {
+        endpoint: endpoint.to_owned(),
+    };
+    match tokio::time::timeout(api.timeout, api.transport.send(request)).await {
+        Err(_) => Err(timeout()),
+        Ok(Err(Error::RequestError(e))) if e.is_timeout() => Err(timeout()),
+        Ok(result) => result,
+    }
+}
+
+/// Deserializes the body of the `response` of `endpoint` into `T`, leniently if requested.
+fn parse<T: Parseable>(api: &HellApi, endpoint: &str, response: &Response) -> Result<T> {
+    let result = match lenient::is_active() {
+        true => lenient::parse(endpoint, &response.body),
+        false => T::parse(&response.body),
+    };
+    result.map_err(|error| match error {
+        Error::ParseError { path, source, .. } => Error::ParseError {
+            endpoint: Some(endpoint.to_owned()),
+            path,
+            source,
+            payload: api
+                .dump_payloads
+                .then(|| String::from_utf8_lossy(&response.body).into_owned()),
+        },
+        error => error,
+    })
+}
+
+/// Maps unsuccessful responses to the matching error.
+fn check_status(endpoint: &str, response: &Response) -> Result<()> {
+    let endpoint = endpoint.to_owned();
+    match response.status {
+        StatusCode::OK | StatusCode::NOT_MODIFIED => Ok(()),
+        StatusCode::NOT_FOUND => Err(Error::NotFound { endpoint }),
+        StatusCode::GATEWAY_TIMEOUT => Err(Error::Timeout { endpoint }),
+        status @ (StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE) => {
+            Err(Error::ServiceUnavailable { endpoint, status })
+        }
+        status => Err(Error::HttpStatus {
+            endpoint,
+            status,
+            body_snippet: String::from_utf8_lossy(&response.body)
+                .chars()
+                .take(BODY_SNIPPET_LENGTH)
+                .collect(),
+        }),
+    }
+}
+
+/// Returns the cached response of `endpoint` if `api` has a cache.
+fn cached(api: &HellApi, endpoint: &str) -> Option<Response> {
+    api.cache.as_ref()?.get(endpoint)
+}
+
+/// Returns what is needed to revalidate the last response of `endpoint`, if anything.
+/// Lenient requests are never conditional so they report their warnings.
+fn revalidation<T: Parseable>(api: &HellApi, endpoint: &str) -> Option<Revalidation<T>> {
+    if lenient::is_active() {
+        return None;
+    }
+    api.validators.as_ref()?.get(endpoint)
+}
+
+/// Deserializes the body of a successful `response` into `T` and keeps it for later requests
+/// unless it was parsed leniently. A `304 Not Modified` returns the value of the revalidated
+/// response instead.
+fn finish<T: Parseable>(
+    api: &HellApi,
+    endpoint: &str,
+    response: Response,
+    revalidation: Option<Revalidation<T>>,
+) -> Result<T> {
+    let (response, value) = match (response.status, revalidation) {
+        (StatusCode::NOT_MODIFIED, Some(revalidation)) => {
+            (revalidation.response, revalidation.value)
+        }
+        (StatusCode::NOT_MODIFIED, None) => {
+            return Err(Error::HttpStatus {
+                endpoint: endpoint.to_owned(),
+                status: response.status,
+                body_snippet: String::new(),
+            })
+        }
+        _ => {
+            let value = parse(api, endpoint, &response)?;
+            (response, value)
         }
+    };
+    // a lenient value may lack elements a strict request would fail on
+    if lenient::is_active() {
+        return Ok(value);
+    }
+    if let Some(validators) = &api.validators {
+        validators.insert(endpoint, &response, &value);
     }
+    if let Some(cache) = &api.cache {
+        cache.insert(endpoint, response);
+    }
+
+    Ok(value)
 }
 
-#[allow(dead_code)]
-pub(crate) async fn request<T: Parseable>(endpoint: &str) -> Result<T> {
-    if let Err(duration) = RATE_LIMIT.try_wait() {
+/// Requests the API `endpoint` without waiting. Returns [`Error::RateLimitReached`] with the
+/// time to wait if the rate limit of `api` has been reached. Afterwards the JSON response is
+/// deserialized into `T`.
+///
+/// Failed requests are never retried. Cached responses are returned without a request.
+pub(crate) async fn request<T: Parseable>(api: &HellApi, endpoint: &str) -> Result<T> {
+    if let Some(response) = cached(api, endpoint) {
+        return parse(api, endpoint, &response);
+    }
+    if let Err(duration) = api.rate_limit.try_acquire() {
         return Err(Error::RateLimitReached(duration));
     }
 
-    let response = reqwest::get(BASE_URL.to_owned() + endpoint).await?;
-    RATE_LIMIT.update(response.headers());
+    let revalidation = revalidation(api, endpoint);
+    let conditions = revalidation.as_ref().map(|r| r.headers.clone());
+    let response = get(api, endpoint, &conditions.unwrap_or_default()).await?;
+    api.rate_limit.update(&response.headers);
+    if response.status == StatusCode::TOO_MANY_REQUESTS {
+        let duration = api.rate_limit.next_permit_in(Instant::now());
+        return Err(Error::RateLimitReached(duration));
+    }
+    check_status(endpoint, &response)?;
 
-    let json: serde_json::Value = response.json().await.map_err(Error::RequestError)?;
+    finish(api, endpoint, response, revalidation)
+}
+
+/// Waits until `api` may send a request without exceeding its rate limit.
+pub(crate) async fn wait_until_ready(api: &HellApi) {
+    api.rate_limit.ready().await;
+}
 
-    T::parse(json)
+/// Sends a single request for `endpoint` and returns the response if it was successful.
+async fn attempt(api: &HellApi, endpoint: &str, conditions: &HeaderMap) -> Result<Response> {
+    let response = get(api, endpoint, conditions).await?;
+    api.rate_limit.update(&response.headers);
+    check_status(endpoint, &response)?;
+
+    Ok(response)
 }
 
-/// Requests the API `endpoint` blocking the current thread when the `rate` limit has been reached.
-/// Afterwards the JSON response is deserialized into `T`.
-pub(crate) async fn request_blocking<T: Parseable>(endpoint: &str) -> Result<T> {
-    // block until ready
+/// Requests the API `endpoint` blocking the current thread when the rate limit of `api` has been
+/// reached. Afterwards the JSON response is deserialized into `T`.
+///
+/// Transient failures are retried according to the [`RetryPolicy`](crate::retry::RetryPolicy)
+/// of `api`. If more than one attempt was made, the final error is wrapped in [`Error::Retry`],
+/// even if it is not retryable itself. Cached responses are returned without a request.
+///
+/// Concurrent calls for the same endpoint are coalesced into a single request whose result
+/// is returned to every caller. Its errors are wrapped in [`Error::Shared`] for all callers but
+/// the one that sent the request. Lenient requests are not coalesced.
+pub(crate) async fn request_blocking<T: Parseable>(api: &HellApi, endpoint: &str) -> Result<T> {
+    if lenient::is_active() {
+        return fetch_blocking(api, endpoint).await;
+    }
+    api.in_flight
+        .coalesce(endpoint, || fetch_blocking(api, endpoint))
+        .await
+}
+
+/// Performs the request of [`request_blocking()`] without coalescing.
+async fn fetch_blocking<T: Parseable>(api: &HellApi, endpoint: &str) -> Result<T> {
+    if let Some(response) = cached(api, endpoint) {
+        return parse(api, endpoint, &response);
+    }
+
+    let revalidation = revalidation(api, endpoint);
+    let conditions = revalidation.as_ref().map(|r| r.headers.clone());
+    let conditions = conditions.unwrap_or_default();
+    let mut attempts = 0;
     let response = loop {
-        if let Err(wait_for) = RATE_LIMIT.try_wait() {
-            tokio::time::sleep(wait_for).await;
-            if let Ok(response) = reqwest::get(BASE_URL.to_owned() + endpoint).await {
-                if response.status() == 200 {
-                    break response;
-                } else {
-                    RATE_LIMIT.update(response.headers());
-                }
-            };
-        } else {
-            break reqwest::get(BASE_URL.to_owned() + endpoint).await?;
+        // block until ready
+        api.rate_limit.acquire().await;
+        attempts += 1;
+
+        match attempt(api, endpoint, &conditions).await {
+            Ok(response) => break response,
+            Err(error) if api.retry.should_retry(attempts, &error) => {
+                tokio::time::sleep(api.retry.backoff(attempts)).await;
+            }
+            Err(error) if attempts > 1 => {
+                return Err(Error::Retry {
+                    attempts,
+                    source: Box::new(error),
+                })
+            }
+            Err(error) => return Err(error),
         }
     };
 
-    RATE_LIMIT.update(response.headers());
-    let json = response.json().await.map_err(Error::RequestError)?;
+    finish(api, endpoint, response, revalidation)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use reqwest::{
+        header::{HeaderMap, HeaderValue, ETAG, IF_NONE_MATCH},
+        StatusCode,
+    };
+    use tokio::time::Instant;
+
+    use crate::{
+        lenient::lenient,
+        models::raw::war::WarId,
+        prelude::{Error, Parseable, Result},
+        retry::RetryPolicy,
+        transport::{InMemoryTransport, Request, Response, Transport},
+        HellApi,
+    };
+
+    use super::RateLimit;
+
+    const WAR_ID: &str = "/raw/api/WarSeason/current/WarID";
+
+    fn ok(body: &'static str) -> Response {
+        Response::ok(body)
+            .with_header("X-Ratelimit-Limit".parse().unwrap(), HeaderValue::from(5))
+            .with_header(
+                "X-Ratelimit-Remaining".parse().unwrap(),
+                HeaderValue::from(4),
+            )
+    }
+
+    fn client(transport: &Arc<InMemoryTransport>) -> HellApi {
+        HellApi::builder()
+            .transport(transport.clone())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limit_update() {
+        let rate_limit = RateLimit::new(5, Duration::from_secs(10));
+        assert_eq!(rate_limit.snapshot().remaining, 5);
+        assert_eq!(rate_limit.snapshot().reset, None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Ratelimit-Limit", HeaderValue::from(10));
+        headers.insert("X-Ratelimit-Remaining", HeaderValue::from(3));
+        rate_limit.update(&headers);
+
+        let snapshot = rate_limit.snapshot();
+        assert_eq!(snapshot.limit, 10);
+        assert_eq!(snapshot.remaining, 3);
+        assert!(snapshot.reset.is_some());
+        assert_eq!(rate_limit.try_acquire(), Ok(()));
+
+        headers.insert("X-Ratelimit-Remaining", HeaderValue::from(0));
+        headers.insert("X-Ratelimit-Reset", HeaderValue::from(4));
+        rate_limit.update(&headers);
+        assert_eq!(rate_limit.try_acquire(), Err(Duration::from_secs(4)));
+
+        headers.insert("Retry-After", HeaderValue::from(7));
+        rate_limit.update(&headers);
+        assert_eq!(rate_limit.try_acquire(), Err(Duration::from_secs(7)));
+
+        tokio::time::advance(Duration::from_secs(7)).await;
+        assert_eq!(rate_limit.try_acquire(), Ok(()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limit_refills_over_window() {
+        let rate_limit = RateLimit::new(5, Duration::from_secs(10));
+        for _ in 0..5 {
+            assert_eq!(rate_limit.try_acquire(), Ok(()));
+        }
+        assert_eq!(rate_limit.try_acquire(), Err(Duration::from_secs(2)));
+
+        tokio::time::advance(Duration::from_secs(2)).await;
+        assert_eq!(rate_limit.try_acquire(), Ok(()));
+        assert_eq!(rate_limit.snapshot().remaining, 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn ready_does_not_take_a_permit() {
+        let rate_limit = RateLimit::new(1, Duration::from_secs(10));
+        assert_eq!(rate_limit.try_acquire(), Ok(()));
+        let start = Instant::now();
+
+        rate_limit.ready().await;
+        rate_limit.ready().await;
+
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+        assert_eq!(rate_limit.try_acquire(), Ok(()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_tasks_share_permits() {
+        let rate_limit = Arc::new(RateLimit::new(5, Duration::from_secs(10)));
+        let start = Instant::now();
+
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let rate_limit = rate_limit.clone();
+                tokio::spawn(async move {
+                    rate_limit.acquire().await;
+                    start.elapsed()
+                })
+            })
+            .collect();
+        let mut elapsed = Vec::new();
+        for task in tasks {
+            elapsed.push(task.await.unwrap());
+        }
+        elapsed.sort();
+
+        let immediate = elapsed.iter().filter(|e| e.is_zero()).count();
+        assert_eq!(immediate, 5);
+        assert_eq!(elapsed[9], Duration::from_secs(10));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn first_request_is_not_delayed() {
+        let transport =
+            Arc::new(InMemoryTransport::new().with_response(WAR_ID, ok(r#"{"id":801}"#)));
+        let api = client(&transport);
+        let start = Instant::now();
+
+        api.war_id().await.unwrap();
+
+        assert!(start.elapsed().is_zero());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_blocking_retries_after_rate_limit() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .with_response(
+                    WAR_ID,
+                    Response::new(StatusCode::TOO_MANY_REQUESTS, "")
+                        .with_header("Retry-After".parse().unwrap(), HeaderValue::from(3)),
+                )
+                .with_response(WAR_ID, ok(r#"{"id":801}"#)),
+        );
+        let api = client(&transport);
+        let start = Instant::now();
+
+        let war_id: WarId = api.war_id().await.unwrap();
+
+        assert_eq!(war_id.id, 801);
+        assert_eq!(transport.requests().len(), 2);
+        assert!(start.elapsed() >= Duration::from_secs(3));
+    }
+
+    /// Drops the connection of the first `failures` requests.
+    #[derive(Debug)]
+    struct Flaky {
+        failures: AtomicU32,
+        inner: InMemoryTransport,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for Flaky {
+        async fn send(&self, request: Request) -> Result<Response> {
+            let failures = &self.failures;
+            if failures
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                return Err(io::Error::from(io::ErrorKind::ConnectionReset).into());
+            }
+            self.inner.send(request).await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_blocking_retries_transient_errors() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .with_response(WAR_ID, Response::new(StatusCode::SERVICE_UNAVAILABLE, ""))
+                .with_response(WAR_ID, Response::new(StatusCode::BAD_GATEWAY, ""))
+                .with_response(WAR_ID, ok(r#"{"id":801}"#)),
+        );
+        let api = client(&transport);
+
+        let war_id = api.war_id().await.unwrap();
+
+        assert_eq!(war_id.id, 801);
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_blocking_retries_dropped_connections() {
+        let transport = Flaky {
+            failures: AtomicU32::new(2),
+            inner: InMemoryTransport::new().with_response(WAR_ID, ok(r#"{"id":801}"#)),
+        };
+        let api = HellApi::builder().transport(transport).build().unwrap();
+
+        assert_eq!(api.war_id().await.unwrap().id, 801);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_blocking_gives_up_after_max_attempts() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .with_response(WAR_ID, Response::new(StatusCode::GATEWAY_TIMEOUT, "")),
+        );
+        let api = HellApi::builder()
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::new(3))
+            .build()
+            .unwrap();
 
-    T::parse(json)
+        let result = api.war_id().await;
+
+        match result {
+            Err(Error::Retry { attempts, source }) => {
+                assert_eq!(attempts, 3);
+                assert!(matches!(*source, Error::Timeout { .. }));
+            }
+            other => panic!("expected Retry error, got {other:?}"),
+        }
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_blocking_does_not_retry_fatal_errors() {
+        let transport = Arc::new(InMemoryTransport::new());
+        let api = client(&transport);
+
+        let result = api.planet(9999).await;
+
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_blocking_counts_attempts_before_fatal_errors() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .with_response(WAR_ID, Response::new(StatusCode::SERVICE_UNAVAILABLE, ""))
+                .with_response(WAR_ID, Response::new(StatusCode::NOT_FOUND, "")),
+        );
+        let api = client(&transport);
+
+        let result = api.war_id().await;
+
+        match result {
+            Err(Error::Retry { attempts, source }) => {
+                assert_eq!(attempts, 2);
+                assert!(matches!(*source, Error::NotFound { .. }));
+            }
+            other => panic!("expected Retry error, got {other:?}"),
+        }
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn request_fails_fast_when_rate_limited() {
+        let transport = Arc::new(InMemoryTransport::new().with_response(WAR_ID, ok("{}")));
+        let api = HellApi::builder()
+            .transport(transport.clone())
+            .rate_limit(1, Duration::from_secs(10))
+            .build()
+            .unwrap();
+        api.rate_limit.try_acquire().unwrap();
+
+        let result = super::request::<WarId>(&api, WAR_ID).await;
+
+        assert!(matches!(result, Err(Error::RateLimitReached(_))));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn request_parses_response() {
+        let transport =
+            Arc::new(InMemoryTransport::new().with_response(WAR_ID, ok(r#"{"id":801}"#)));
+        let api = client(&transport);
+
+        let war_id = super::request::<WarId>(&api, WAR_ID).await.unwrap();
+
+        assert_eq!(war_id.id, 801);
+        assert_eq!(transport.requests()[0].path, WAR_ID);
+        assert_eq!(api.rate_limit().remaining, 4);
+    }
+
+    #[tokio::test]
+    async fn request_reports_parse_errors() {
+        let transport =
+            Arc::new(InMemoryTransport::new().with_response(WAR_ID, ok(r#"{"id":"x"}"#)));
+        let api = client(&transport);
+
+        let result = super::request::<WarId>(&api, WAR_ID).await;
+
+        match result {
+            Err(Error::ParseError {
+                endpoint,
+                path,
+                payload,
+                ..
+            }) => {
+                assert_eq!(endpoint.as_deref(), Some(WAR_ID));
+                assert_eq!(path, "id");
+                assert_eq!(payload, None);
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+
+    /// Answers every request after a second.
+    #[derive(Debug)]
+    struct Slow(Arc<InMemoryTransport>);
+
+    #[async_trait::async_trait]
+    impl Transport for Slow {
+        async fn send(&self, request: Request) -> Result<Response> {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            self.0.send(request).await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stalled_requests_time_out() {
+        let transport =
+            Arc::new(InMemoryTransport::new().with_response(WAR_ID, ok(r#"{"id":801}"#)));
+        let api = HellApi::builder()
+            .transport(Slow(transport.clone()))
+            .timeout(Duration::from_millis(500))
+            .retry_policy(RetryPolicy::never())
+            .build()
+            .unwrap();
+
+        let result = api.war_id().await;
+
+        assert!(matches!(result, Err(Error::Timeout { .. })));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_requests_are_coalesced() {
+        let transport =
+            Arc::new(InMemoryTransport::new().with_response(WAR_ID, ok(r#"{"id":801}"#)));
+        let api = HellApi::builder()
+            .transport(Slow(transport.clone()))
+            .build()
+            .unwrap();
+
+        let (first, second, third) = tokio::join!(api.war_id(), api.war_id(), api.war_id());
+
+        assert_eq!(first.unwrap().id, 801);
+        assert_eq!(second.unwrap().id, 801);
+        assert_eq!(third.unwrap().id, 801);
+        assert_eq!(transport.requests().len(), 1);
+        assert_eq!(api.rate_limit().remaining, 4);
+    }
+
+    #[tokio::test]
+    async fn not_modified_returns_previous_value() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .with_response(
+                    WAR_ID,
+                    ok(r#"{"id":801}"#).with_header(ETAG, HeaderValue::from_static("\"801\"")),
+                )
+                .with_response(WAR_ID, Response::new(StatusCode::NOT_MODIFIED, "")),
+        );
+        let api = client(&transport);
+
+        let first = api.war_id().await.unwrap();
+        let second = api.war_id().await.unwrap();
+        let third = super::request::<WarId>(&api, WAR_ID).await.unwrap();
+
+        assert_eq!((first.id, second.id, third.id), (801, 801, 801));
+        let requests = transport.requests();
+        assert_eq!(requests[0].headers.get(IF_NONE_MATCH), None);
+        assert_eq!(requests[1].headers[IF_NONE_MATCH], "\"801\"");
+        assert_eq!(requests[2].headers[IF_NONE_MATCH], "\"801\"");
+    }
+
+    #[tokio::test]
+    async fn conditional_requests_can_be_disabled() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .with_response(
+                    WAR_ID,
+                    ok(r#"{"id":801}"#).with_header(ETAG, HeaderValue::from_static("\"801\"")),
+                )
+                .with_response(WAR_ID, Response::new(StatusCode::NOT_MODIFIED, "")),
+        );
+        let api = HellApi::builder()
+            .transport(transport.clone())
+            .conditional_requests(false)
+            .build()
+            .unwrap();
+
+        api.war_id().await.unwrap();
+        let result = api.war_id().await;
+
+        assert!(transport.requests()[1].headers.get(IF_NONE_MATCH).is_none());
+        assert!(matches!(
+            result,
+            Err(Error::HttpStatus {
+                status: StatusCode::NOT_MODIFIED,
+                ..
+            })
+        ));
+    }
+
+    impl Parseable for Vec<u8> {}
+
+    #[tokio::test]
+    async fn lenient_values_are_not_revalidated() {
+        const IDS: &str = "/ids";
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .with_response(
+                    IDS,
+                    ok("[1, -1, 2]").with_header(ETAG, HeaderValue::from_static("\"ids\"")),
+                )
+                .with_response(IDS, Response::new(StatusCode::NOT_MODIFIED, "")),
+        );
+        let api = client(&transport);
+
+        let ids = lenient(super::request_blocking::<Vec<u8>>(&api, IDS));
+        let ids = ids.await.unwrap();
+        let result = super::request_blocking::<Vec<u8>>(&api, IDS).await;
+
+        assert_eq!(ids.value, [1, 2]);
+        assert!(transport.requests()[1].headers.get(IF_NONE_MATCH).is_none());
+        assert!(matches!(
+            result,
+            Err(Error::HttpStatus {
+                status: StatusCode::NOT_MODIFIED,
+                ..
+            })
+        ));
+    }
 }
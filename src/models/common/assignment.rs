@@ -1,36 +1,390 @@
-use serde::Deserialize;
-use serde_repr::Deserialize_repr;
+use serde::{Deserialize, Serialize};
+
+use super::{faction::Faction, Extra};
 
 /// Represents a task type for an Assignment. Its exact values are not
-/// known but some have been found.
+/// known but some have been found by the community.
 #[non_exhaustive]
-#[repr(i32)]
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "i32", into = "i32")]
 pub enum TaskType {
+    /// Extract with collected items, e.g. samples.
+    Gather,
     /// Eradicate the opposing faction.
-    Eradication = 3,
+    Eradication,
+    /// Complete missions.
+    Missions,
+    /// Complete operations.
+    Operations,
     /// Liberate the supplied planets.
-    Liberation = 11,
+    Liberation,
     /// Defend against opposing forces.
     Defense,
-    /// FIXME More information needed.
+    /// Hold the supplied planets.
     Control,
+    /// Liberate any planets of a faction.
+    Expansion,
+    /// A task type unknown to this crate.
+    Unknown(i32),
+}
+
+impl From<i32> for TaskType {
+    fn from(value: i32) -> Self {
+        match value {
+            2 => TaskType::Gather,
+            3 => TaskType::Eradication,
+            7 => TaskType::Missions,
+            9 => TaskType::Operations,
+            11 => TaskType::Liberation,
+            12 => TaskType::Defense,
+            13 => TaskType::Control,
+            15 => TaskType::Expansion,
+            value => TaskType::Unknown(value),
+        }
+    }
 }
 
-/// Represents a task in an Assignment. Its exact values are not known and
-/// little of its purpose is clear.
+impl From<TaskType> for i32 {
+    fn from(value: TaskType) -> Self {
+        match value {
+            TaskType::Gather => 2,
+            TaskType::Eradication => 3,
+            TaskType::Missions => 7,
+            TaskType::Operations => 9,
+            TaskType::Liberation => 11,
+            TaskType::Defense => 12,
+            TaskType::Control => 13,
+            TaskType::Expansion => 15,
+            TaskType::Unknown(value) => value,
+        }
+    }
+}
+
+/// Represents the meaning of a value of a `Task`. Only some values are known.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum ValueType {
+    /// The identifier of a faction, `0` meaning any faction.
+    Faction,
+    /// The amount needed to complete the task, e.g. kills or planets.
+    TargetCount,
+    /// The identifier of an enemy type, `0` meaning any enemy.
+    EnemyId,
+    /// The identifier of an item, `0` meaning any item.
+    ItemId,
+    /// Whether the planet has to be liberated.
+    Liberation,
+    /// The index of a planet.
+    PlanetIndex,
+    /// A value type unknown to this crate.
+    Unknown(i32),
+}
+
+impl From<i32> for ValueType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ValueType::Faction,
+            3 => ValueType::TargetCount,
+            4 => ValueType::EnemyId,
+            5 => ValueType::ItemId,
+            11 => ValueType::Liberation,
+            12 => ValueType::PlanetIndex,
+            value => ValueType::Unknown(value),
+        }
+    }
+}
+
+impl From<ValueType> for i32 {
+    fn from(value: ValueType) -> Self {
+        match value {
+            ValueType::Faction => 1,
+            ValueType::TargetCount => 3,
+            ValueType::EnemyId => 4,
+            ValueType::ItemId => 5,
+            ValueType::Liberation => 11,
+            ValueType::PlanetIndex => 12,
+            ValueType::Unknown(value) => value,
+        }
+    }
+}
+
+/// Represents a task in an Assignment. Use [`Task::objective()`] to find out what it asks for.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct Task {
     /// Numerical value. Only some values are known
     #[serde(rename = "type")]
     pub task_type: TaskType,
-    /// A list of numerical values. Purpose unknown. FIXME
+    /// The values of the task. Their meaning is given by `value_types`.
     pub values: Vec<i32>,
-    /// A list of numerical values. Purpose unknown. FIXME
+    /// The meaning of each entry of `values`.
     #[serde(rename = "valueTypes")]
-    pub value_types: Vec<i32>,
+    pub value_types: Vec<ValueType>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Task {
+    /// Returns the first value of type `value_type`.
+    pub fn value(&self, value_type: ValueType) -> Option<i32> {
+        self.value_types
+            .iter()
+            .zip(&self.values)
+            .find(|(other, _)| **other == value_type)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the faction the task refers to, if it is restricted to one.
+    pub fn faction(&self) -> Option<Faction> {
+        self.value(ValueType::Faction)
+            .filter(|id| *id != 0)
+            .map(Faction::from)
+    }
+
+    /// Decodes what the task asks for. Tasks that cannot be decoded are returned as
+    /// [`Objective::Other`].
+    pub fn objective(&self) -> Objective {
+        let objective = match self.task_type {
+            TaskType::Liberation => self
+                .value(ValueType::PlanetIndex)
+                .map(|planet| Objective::LiberatePlanet { planet }),
+            TaskType::Defense => {
+                self.value(ValueType::TargetCount)
+                    .map(|count| Objective::DefendPlanets {
+                        count,
+                        faction: self.faction(),
+                    })
+            }
+            TaskType::Eradication => {
+                self.value(ValueType::TargetCount)
+                    .map(|kills| Objective::Eradicate {
+                        faction: self.faction(),
+                        enemy: self.value(ValueType::EnemyId).filter(|id| *id != 0),
+                        kills,
+                    })
+            }
+            TaskType::Control => self
+                .value(ValueType::PlanetIndex)
+                .map(|planet| Objective::Control { planet }),
+            _ => None,
+        };
+
+        objective.unwrap_or_else(|| Objective::Other {
+            task_type: self.task_type,
+            values: self
+                .value_types
+                .iter()
+                .copied()
+                .zip(self.values.iter().copied())
+                .collect(),
+        })
+    }
+}
+
+/// Represents what a `Task` asks of the Helldivers.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Objective {
+    /// Liberate the planet with index `planet`.
+    LiberatePlanet { planet: i32 },
+    /// Successfully defend `count` planets against `faction` or any faction.
+    DefendPlanets {
+        count: i32,
+        faction: Option<Faction>,
+    },
+    /// Kill `kills` enemies of `faction` or any faction, optionally of one enemy type.
+    Eradicate {
+        faction: Option<Faction>,
+        enemy: Option<i32>,
+        kills: i32,
+    },
+    /// Hold the planet with index `planet`.
+    Control { planet: i32 },
+    /// A task this crate cannot decode yet together with its typed values.
+    Other {
+        task_type: TaskType,
+        values: Vec<(ValueType, i32)>,
+    },
+}
+
+/// Represents the kind of reward of an assignment. Only some values are known.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum RewardType {
+    /// Medals to spend on warbonds.
+    Medals,
+    /// A reward type unknown to this crate.
+    Unknown(i32),
+}
+
+impl From<i32> for RewardType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => RewardType::Medals,
+            value => RewardType::Unknown(value),
+        }
+    }
+}
+
+impl From<RewardType> for i32 {
+    fn from(value: RewardType) -> Self {
+        match value {
+            RewardType::Medals => 1,
+            RewardType::Unknown(value) => value,
+        }
+    }
+}
+
+/// The progress of a single `Task` of an assignment.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskProgress<'a> {
+    /// The task the progress belongs to.
+    pub task: &'a Task,
+    /// The amount achieved so far, e.g. kills or `1` for a liberated planet.
+    pub progress: i32,
+    /// The amount needed to complete the task. Tasks without a target count need `1`.
+    pub target: i32,
+}
+
+impl TaskProgress<'_> {
+    /// Returns the completion of the task between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f64 {
+        (f64::from(self.progress) / f64::from(self.target)).clamp(0.0, 1.0)
+    }
+
+    /// Returns whether the task has been completed.
+    pub fn is_complete(&self) -> bool {
+        self.progress >= self.target
+    }
+}
+
+/// Pairs each of the `tasks` of an assignment with its entry in `progress`.
+pub(crate) fn task_progress<'a>(tasks: &'a [Task], progress: &[i32]) -> Vec<TaskProgress<'a>> {
+    tasks
+        .iter()
+        .enumerate()
+        .map(|(index, task)| TaskProgress {
+            task,
+            progress: progress.get(index).copied().unwrap_or_default(),
+            target: task
+                .value(ValueType::TargetCount)
+                .filter(|target| *target > 0)
+                .unwrap_or(1),
+        })
+        .collect()
+}
+
+/// Returns the mean completion of all `tasks` between `0.0` and `1.0`.
+pub(crate) fn completion(tasks: &[TaskProgress]) -> f64 {
+    if tasks.is_empty() {
+        return 0.0;
+    }
+    tasks.iter().map(TaskProgress::fraction).sum::<f64>() / tasks.len() as f64
 }
 
 pub type JointOperationId = i32;
 pub type CampaignId = i32;
+
+#[cfg(test)]
+mod tests {
+    use crate::models::common::faction::Faction;
+
+    use super::{completion, task_progress, Objective, RewardType, Task, TaskType, ValueType};
+
+    fn task(task_type: i32, values: &[(i32, i32)]) -> Task {
+        let json = serde_json::json!({
+            "type": task_type,
+            "values": values.iter().map(|(_, value)| value).collect::<Vec<_>>(),
+            "valueTypes": values.iter().map(|(value_type, _)| value_type).collect::<Vec<_>>(),
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn decode_objectives() {
+        let liberation = task(11, &[(3, 1), (11, 1), (12, 127)]);
+        assert_eq!(
+            liberation.objective(),
+            Objective::LiberatePlanet { planet: 127 }
+        );
+
+        let defense = task(12, &[(1, 3), (3, 5)]);
+        assert_eq!(
+            defense.objective(),
+            Objective::DefendPlanets {
+                count: 5,
+                faction: Some(Faction::Automaton),
+            }
+        );
+
+        let eradication = task(
+            3,
+            &[(1, 2), (2, 0), (3, 500000000), (4, 0), (5, 0), (11, 0)],
+        );
+        assert_eq!(
+            eradication.objective(),
+            Objective::Eradicate {
+                faction: Some(Faction::Terminids),
+                enemy: None,
+                kills: 500000000,
+            }
+        );
+
+        let control = task(13, &[(3, 1), (12, 161)]);
+        assert_eq!(control.objective(), Objective::Control { planet: 161 });
+    }
+
+    #[test]
+    fn undecodable_tasks_keep_their_values() {
+        let unknown = task(42, &[(1, 0), (9, 7)]);
+        assert_eq!(
+            unknown.objective(),
+            Objective::Other {
+                task_type: TaskType::Unknown(42),
+                values: vec![(ValueType::Faction, 0), (ValueType::Unknown(9), 7)],
+            }
+        );
+
+        let incomplete = task(11, &[(3, 1)]);
+        assert!(matches!(incomplete.objective(), Objective::Other { .. }));
+        assert_eq!(
+            serde_json::to_value(&unknown).unwrap(),
+            serde_json::json!({"type": 42, "values": [0, 7], "valueTypes": [1, 9]})
+        );
+    }
+
+    #[test]
+    fn pair_tasks_with_progress() {
+        let tasks = [
+            task(11, &[(3, 1), (11, 1), (12, 127)]),
+            task(3, &[(1, 2), (3, 400)]),
+            task(13, &[(12, 161)]),
+        ];
+
+        let progress = task_progress(&tasks, &[1, 250]);
+
+        assert_eq!(
+            progress
+                .iter()
+                .map(|task| (task.progress, task.target, task.is_complete()))
+                .collect::<Vec<_>>(),
+            [(1, 1, true), (250, 400, false), (0, 1, false)]
+        );
+        assert_eq!(progress[1].fraction(), 0.625);
+        assert!((completion(&progress) - 1.625 / 3.0).abs() < 1e-9);
+        assert_eq!(completion(&[]), 0.0);
+    }
+
+    #[test]
+    fn parse_reward_types() {
+        let types: Vec<RewardType> = serde_json::from_str("[1, 897]").unwrap();
+
+        assert_eq!(types, [RewardType::Medals, RewardType::Unknown(897)]);
+        assert_eq!(serde_json::to_string(&types).unwrap(), "[1,897]");
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the type of a campaign. Only some values are known.
+///
+/// The type does not tell liberations and defenses apart, both are
+/// [`Liberation`](CampaignType::Liberation). Whether a campaign defends its planet follows from
+/// the [`PlanetEventType`](super::planet::PlanetEventType) of the planet's event instead.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum CampaignType {
+    /// A regular front liberating or defending a planet.
+    Liberation,
+    /// A campaign type unknown to this crate.
+    Unknown(i32),
+}
+
+impl From<i32> for CampaignType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => CampaignType::Liberation,
+            value => CampaignType::Unknown(value),
+        }
+    }
+}
+
+impl From<CampaignType> for i32 {
+    fn from(value: CampaignType) -> Self {
+        match value {
+            CampaignType::Liberation => 0,
+            CampaignType::Unknown(value) => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CampaignType;
+
+    #[test]
+    fn parse_campaign_types() {
+        let types: Vec<CampaignType> = serde_json::from_str("[0, 7]").unwrap();
+
+        assert_eq!(types, [CampaignType::Liberation, CampaignType::Unknown(7)]);
+        assert_eq!(serde_json::to_string(&types).unwrap(), "[0,7]");
+    }
+}
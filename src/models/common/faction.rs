@@ -0,0 +1,171 @@
+use std::fmt;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Represents a faction fighting in the galactic war.
+///
+/// The v1 API names factions while the raw API uses numerical identifiers. Both are accepted
+/// when deserializing.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Faction {
+    Humans,
+    Terminids,
+    Automaton,
+    Illuminate,
+    /// A faction identifier unknown to this crate.
+    UnknownId(i32),
+    /// A faction name unknown to this crate.
+    UnknownName(String),
+}
+
+impl Faction {
+    /// The identifier of the faction in the raw API, if known.
+    pub fn id(&self) -> Option<i32> {
+        match self {
+            Faction::Humans => Some(1),
+            Faction::Terminids => Some(2),
+            Faction::Automaton => Some(3),
+            Faction::Illuminate => Some(4),
+            Faction::UnknownId(id) => Some(*id),
+            Faction::UnknownName(_) => None,
+        }
+    }
+
+    /// The name of the faction in the v1 API, if known.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Faction::Humans => Some("Humans"),
+            Faction::Terminids => Some("Terminids"),
+            Faction::Automaton => Some("Automaton"),
+            Faction::Illuminate => Some("Illuminate"),
+            Faction::UnknownId(_) => None,
+            Faction::UnknownName(name) => Some(name),
+        }
+    }
+
+    /// Serializes the faction as its identifier like the raw API does.
+    pub(crate) fn serialize_id<S: Serializer>(
+        faction: &Faction,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match faction.id() {
+            Some(id) => serializer.serialize_i32(id),
+            None => faction.serialize(serializer),
+        }
+    }
+}
+
+impl From<i32> for Faction {
+    fn from(id: i32) -> Self {
+        match id {
+            1 => Faction::Humans,
+            2 => Faction::Terminids,
+            3 => Faction::Automaton,
+            4 => Faction::Illuminate,
+            id => Faction::UnknownId(id),
+        }
+    }
+}
+
+impl From<&str> for Faction {
+    fn from(name: &str) -> Self {
+        match name {
+            "Humans" => Faction::Humans,
+            "Terminids" => Faction::Terminids,
+            "Automaton" => Faction::Automaton,
+            "Illuminate" => Faction::Illuminate,
+            name => Faction::UnknownName(name.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Faction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Faction::UnknownId(id) => write!(f, "Unknown faction {id}"),
+            faction => f.write_str(faction.name().unwrap_or_default()),
+        }
+    }
+}
+
+impl Serialize for Faction {
+    /// Serializes the faction as its name like the v1 API does.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Faction::UnknownId(id) => serializer.serialize_i32(*id),
+            faction => serializer.serialize_str(faction.name().unwrap_or_default()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Faction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FactionVisitor;
+
+        impl Visitor<'_> for FactionVisitor {
+            type Value = Faction;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a faction name or identifier")
+            }
+
+            fn visit_i64<E: de::Error>(self, id: i64) -> Result<Faction, E> {
+                i32::try_from(id)
+                    .map(Faction::from)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(id), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, id: u64) -> Result<Faction, E> {
+                i32::try_from(id)
+                    .map(Faction::from)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(id), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<Faction, E> {
+                Ok(Faction::from(name))
+            }
+        }
+
+        deserializer.deserialize_any(FactionVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Faction;
+
+    #[test]
+    fn parse_ids_and_names() {
+        let factions: Vec<Faction> =
+            serde_json::from_str(r#"[1, "Terminids", 3, "Illuminate", 9, "Squids"]"#).unwrap();
+
+        assert_eq!(
+            factions,
+            [
+                Faction::Humans,
+                Faction::Terminids,
+                Faction::Automaton,
+                Faction::Illuminate,
+                Faction::UnknownId(9),
+                Faction::UnknownName(String::from("Squids")),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&factions).unwrap(),
+            r#"["Humans","Terminids","Automaton","Illuminate",9,"Squids"]"#
+        );
+    }
+
+    #[test]
+    fn serialize_ids() {
+        let mut json = Vec::new();
+        let mut serializer = serde_json::Serializer::new(&mut json);
+        Faction::serialize_id(&Faction::Automaton, &mut serializer).unwrap();
+
+        assert_eq!(json, b"3");
+    }
+}
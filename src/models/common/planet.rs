@@ -1,9 +1,69 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use super::Extra;
 
 /// Represents the coordinates returned by the ArrowHead API.
 #[non_exhaustive]
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct Position {
     pub x: f32,
     pub y: f32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Represents the type of an event on a planet. Only some values are known.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum PlanetEventType {
+    /// The planet is attacked and has to be defended.
+    Defense,
+    /// An event type unknown to this crate.
+    Unknown(i32),
+}
+
+impl PlanetEventType {
+    /// Returns whether the planet has to be defended.
+    pub fn is_defense(&self) -> bool {
+        matches!(self, PlanetEventType::Defense)
+    }
+}
+
+impl From<i32> for PlanetEventType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => PlanetEventType::Defense,
+            value => PlanetEventType::Unknown(value),
+        }
+    }
+}
+
+impl From<PlanetEventType> for i32 {
+    fn from(value: PlanetEventType) -> Self {
+        match value {
+            PlanetEventType::Defense => 1,
+            PlanetEventType::Unknown(value) => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlanetEventType;
+
+    #[test]
+    fn parse_planet_event_types() {
+        let types: Vec<PlanetEventType> = serde_json::from_str("[1, 2]").unwrap();
+
+        assert_eq!(
+            types,
+            [PlanetEventType::Defense, PlanetEventType::Unknown(2)]
+        );
+        assert!(types[0].is_defense());
+        assert!(!types[1].is_defense());
+        assert_eq!(serde_json::to_string(&types).unwrap(), "[1,2]");
+    }
 }
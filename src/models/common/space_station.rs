@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use super::Extra;
+
+/// Represents the resources needed to activate a tactical action and the donations so far.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Cost {
+    /// The identifier of this cost.
+    pub id: String,
+    /// The identifier of the item to donate.
+    #[serde(rename = "itemMixId")]
+    pub item_mix_id: i64,
+    /// The amount needed to activate the action.
+    #[serde(rename = "targetValue")]
+    pub target_value: f64,
+    /// The amount donated so far.
+    #[serde(rename = "currentValue")]
+    pub current_value: f64,
+    /// The amount donated per second recently.
+    #[serde(rename = "deltaPerSecond")]
+    pub delta_per_second: f64,
+    /// The amount a single Helldiver may donate per donation period.
+    #[serde(rename = "maxDonationAmmount")]
+    pub max_donation_amount: i64,
+    /// The length of a donation period in seconds.
+    #[serde(rename = "maxDonationPeriodSeconds")]
+    pub max_donation_period_seconds: i64,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Cost {
+    /// Returns how much of the target has been donated between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f64 {
+        if self.target_value <= 0.0 {
+            return 1.0;
+        }
+        (self.current_value / self.target_value).clamp(0.0, 1.0)
+    }
+
+    /// Returns whether the target has been reached.
+    pub fn is_complete(&self) -> bool {
+        self.current_value >= self.target_value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cost;
+
+    #[test]
+    fn parse_cost() {
+        let json = r#"{
+            "id": "3992382197-0",
+            "itemMixId": 3992382197,
+            "targetValue": 50000,
+            "currentValue": 12500,
+            "deltaPerSecond": 2.5,
+            "maxDonationAmmount": 100,
+            "maxDonationPeriodSeconds": 3600
+        }"#;
+
+        let cost: Cost = serde_json::from_str(json).unwrap();
+
+        assert_eq!(cost.fraction(), 0.25);
+        assert!(!cost.is_complete());
+        assert_eq!(cost.max_donation_amount, 100);
+    }
+}
@@ -1,11 +1,15 @@
-use std::time::Duration;
+use std::{ops::BitOr, time::Duration};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::DurationSeconds;
 
 use crate::{
     middleware,
-    models::common,
+    models::common::{
+        self,
+        assignment::{self as tasks, TaskProgress},
+        Extra,
+    },
     prelude::{Parseable, Result},
     HellApi,
 };
@@ -15,11 +19,11 @@ use super::war::WarId;
 /// Represents an assignment given from Super Earth to the Helldivers.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Assignment {
     /// An internal identifier for this assignment.
     pub id32: i64,
-    /// A list of numbers. How they represent progress is currently unknown.
+    /// The progress of each task in the same order. See [`Assignment::task_progress()`].
     pub progress: Vec<i32>,
     /// The amount of seconds until this assignment expires.
     #[serde(rename = "expiresIn")]
@@ -28,17 +32,34 @@ pub struct Assignment {
     /// Contains detailed information on this assignment like briefing,
     /// rewards, etc.
     pub setting: Setting,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Assignment {
+    /// Returns the progress of each task, e.g. to show "3/5 planets liberated".
+    pub fn task_progress(&self) -> Vec<TaskProgress<'_>> {
+        tasks::task_progress(&self.setting.tasks, &self.progress)
+    }
+
+    /// Returns the completion of the whole assignment between `0.0` and `1.0`, the mean of
+    /// the completion of its tasks.
+    pub fn completion(&self) -> f64 {
+        tasks::completion(&self.task_progress())
+    }
 }
 
 impl Parseable for Vec<Assignment> {}
 
 /// Represents the details of an Assignment like rewards and requirements.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Setting {
-    /// The type of the assignment. Values are unknown at this point.
+    /// The kind of the assignment.
     #[serde(rename = "type")]
-    pub _type: i32,
+    pub _type: AssignmentKind,
     /// The title of the assignment.
     #[serde(rename = "overrideTitle")]
     pub override_title: Option<String>,
@@ -52,15 +73,91 @@ pub struct Setting {
     pub tasks: Vec<Task>,
     /// Information about the reward that players will receive upon completion.
     pub reward: Reward,
-    /// Flags suspected to be a binary OR'd value. The exact purpose is unknown as of now.
-    pub flags: i32,
+    /// Flags of the assignment. Only some bits are known.
+    pub flags: AssignmentFlags,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 pub type Task = common::assignment::Task;
 
+/// Represents the kind of an Assignment. Only some values are known.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum AssignmentKind {
+    /// An order given to the whole community.
+    MajorOrder,
+    /// An assignment kind unknown to this crate.
+    Unknown(i32),
+}
+
+impl AssignmentKind {
+    /// Returns whether the assignment is a Major Order.
+    pub fn is_major_order(&self) -> bool {
+        matches!(self, AssignmentKind::MajorOrder)
+    }
+}
+
+impl From<i32> for AssignmentKind {
+    fn from(value: i32) -> Self {
+        match value {
+            4 => AssignmentKind::MajorOrder,
+            value => AssignmentKind::Unknown(value),
+        }
+    }
+}
+
+impl From<AssignmentKind> for i32 {
+    fn from(value: AssignmentKind) -> Self {
+        match value {
+            AssignmentKind::MajorOrder => 4,
+            AssignmentKind::Unknown(value) => value,
+        }
+    }
+}
+
+/// The flags of an Assignment. The meaning of the bits is not known yet, so all of them are
+/// kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct AssignmentFlags(i32);
+
+impl AssignmentFlags {
+    /// Returns flags with no bit set.
+    pub const fn empty() -> Self {
+        AssignmentFlags(0)
+    }
+
+    /// Returns flags with exactly the given `bits` set, including unknown ones.
+    pub const fn from_bits_retain(bits: i32) -> Self {
+        AssignmentFlags(bits)
+    }
+
+    /// Returns the raw bits.
+    pub const fn bits(&self) -> i32 {
+        self.0
+    }
+
+    /// Returns whether all bits of `other` are set.
+    pub const fn contains(&self, other: AssignmentFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for AssignmentFlags {
+    type Output = AssignmentFlags;
+
+    fn bitor(self, other: AssignmentFlags) -> AssignmentFlags {
+        AssignmentFlags(self.0 | other.0)
+    }
+}
+
 /// Represents the reward of an Assignment.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Reward {
     /// The type of reward.
     #[serde(rename = "type")]
@@ -69,24 +166,62 @@ pub struct Reward {
     pub id32: i32,
     /// The amount the player will receive upon completion.
     pub amount: i32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
-/// The type of a Reward. Currently only one value is known.
-#[non_exhaustive]
-#[repr(i32)]
-#[derive(Debug, Deserialize)]
-#[serde(untagged)]
-pub enum RewardType {
-    Medals = 1,
-    Unknown(i32),
-}
+/// The type of a Reward.
+pub type RewardType = common::assignment::RewardType;
 
 impl HellApi {
     /// Retrieves a list of currently active assignments (like Major Orders).
     ///
     /// Endpoint: `/raw/api/v2/Assignment/War/{war_id}`.
-    pub async fn assignments_raw(war_id: WarId) -> Result<Vec<Assignment>> {
+    pub async fn assignments_raw(&self, war_id: WarId) -> Result<Vec<Assignment>> {
+        let endpoint = format!("/raw/api/v2/Assignment/War/{}", war_id.id);
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::assignments_raw()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/v2/Assignment/War/{war_id}`.
+    pub async fn try_assignments_raw(&self, war_id: WarId) -> Result<Vec<Assignment>> {
         let endpoint = format!("/raw/api/v2/Assignment/War/{}", war_id.id);
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request(self, endpoint.as_str()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssignmentFlags, AssignmentKind};
+
+    #[test]
+    fn flags_keep_all_bits() {
+        let flags: AssignmentFlags = serde_json::from_str("5").unwrap();
+
+        assert!(flags.contains(AssignmentFlags::from_bits_retain(4)));
+        assert!(!flags.contains(AssignmentFlags::from_bits_retain(2)));
+        assert!(flags.contains(AssignmentFlags::empty()));
+        assert_eq!(flags.bits(), 5);
+        assert_eq!(
+            AssignmentFlags::from_bits_retain(1) | AssignmentFlags::from_bits_retain(4),
+            flags
+        );
+        assert_eq!(serde_json::to_string(&flags).unwrap(), "5");
+    }
+
+    #[test]
+    fn parse_assignment_kinds() {
+        let kinds: Vec<AssignmentKind> = serde_json::from_str("[4, 8]").unwrap();
+
+        assert_eq!(
+            kinds,
+            [AssignmentKind::MajorOrder, AssignmentKind::Unknown(8)]
+        );
+        assert!(kinds[0].is_major_order());
+        assert!(!kinds[1].is_major_order());
     }
 }
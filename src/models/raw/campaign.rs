@@ -1,17 +1,43 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::models::common::{campaign::CampaignType, Extra};
+
+use super::planet::PlanetEvent;
 
 /// Represents information about an ongoing campaign.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Campaign {
     /// The identifier of this campaign.
     pub id: i32,
     /// The index of the referred planet.
     #[serde(rename = "planetIndex")]
     pub planet_index: i32,
-    /// The indicator for the type of campaign (see helldivers-2/json).
+    /// The type of campaign.
     #[serde(rename = "type")]
-    pub campaign_type: i32, // TODO enum type CampaignType
+    pub campaign_type: CampaignType,
     /// The amount of campaigns the planet has seen.
     pub count: i32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Campaign {
+    /// Returns whether this campaign defends its planet against an attack, which the
+    /// [`CampaignType`] does not tell. `events` are the
+    /// [`planet_events`](super::war::WarStatus::planet_events) of the status listing this
+    /// campaign.
+    pub fn is_defense(&self, events: &[PlanetEvent]) -> bool {
+        events
+            .iter()
+            .any(|event| event.campaign_id == self.id && event.event_type.is_defense())
+    }
+
+    /// Returns whether this campaign liberates its planet from another faction, see
+    /// [`Campaign::is_defense()`] for `events`.
+    pub fn is_liberation(&self, events: &[PlanetEvent]) -> bool {
+        self.campaign_type == CampaignType::Liberation && !self.is_defense(events)
+    }
 }
@@ -1,9 +1,10 @@
 use chrono::NaiveDateTime;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::TimestampSeconds;
 
 use crate::{
     middleware,
+    models::common::Extra,
     prelude::{Parseable, Result},
     HellApi,
 };
@@ -13,7 +14,7 @@ use super::war::WarId;
 /// Represents an item in the newsfeed of Super Earth.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct NewsFeedItem {
     /// The identifier of this newsfeed item.
     pub id: i32,
@@ -23,8 +24,15 @@ pub struct NewsFeedItem {
     /// A numerical type. Purpose is unknown.
     #[serde(rename = "type")]
     pub _type: i32,
+    /// The tags of the item. Not typed yet, as it is empty in all recorded responses.
+    #[serde(rename = "tagIds", default)]
+    pub tag_ids: Vec<serde_json::Value>,
     /// The message.
     pub message: String,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for Vec<NewsFeedItem> {}
@@ -33,8 +41,17 @@ impl HellApi {
     /// Retrieves a list of news messages from Super Earth.
     ///
     /// Endpoint: `/raw/api/NewsFeed/{war_id}`.
-    pub async fn news_feed(war_id: WarId) -> Result<Vec<NewsFeedItem>> {
+    pub async fn news_feed(&self, war_id: WarId) -> Result<Vec<NewsFeedItem>> {
         let endpoint = format!("/raw/api/NewsFeed/{}", war_id.id);
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::news_feed()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/NewsFeed/{war_id}`.
+    pub async fn try_news_feed(&self, war_id: WarId) -> Result<Vec<NewsFeedItem>> {
+        let endpoint = format!("/raw/api/NewsFeed/{}", war_id.id);
+        middleware::request(self, endpoint.as_str()).await
     }
 }
@@ -1,30 +1,40 @@
 use chrono::NaiveDateTime;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::TimestampSeconds;
 
 use crate::models::common::{
     self,
     assignment::{CampaignId, JointOperationId},
+    faction::Faction,
+    planet::PlanetEventType,
+    Extra,
 };
 
 /// Represents information about the homeworld(s) of a given race (faction).
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HomeWorld {
-    pub race: i32,
+    /// The faction living on the homeworlds.
+    #[serde(serialize_with = "Faction::serialize_id")]
+    pub race: Faction,
     /// A list of planet index identifiers.
     #[serde(rename = "planetIndices")]
     pub planet_indices: Vec<i32>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Represents the current status of a planet in the war.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PlanetStatus {
     /// The identifier of the corresponding PlanetInfo.
     pub index: i32,
     /// The faction currently owning the planet.
-    pub owner: i32,
+    #[serde(serialize_with = "Faction::serialize_id")]
+    pub owner: Faction,
     /// The current liberation of a planet.
     pub health: i64,
     /// The health regeneration if the planet was left alone.
@@ -32,33 +42,42 @@ pub struct PlanetStatus {
     pub regen_per_sec: f64,
     /// The amount of players active on this planet.
     pub players: u64,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Represents an attack on a planet.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PlanetAttack {
     /// The identifier of where the attack originates from.
     pub source: i32,
     /// The planet that is targeted by the attack.
     pub target: i32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Represents an ongoing event on a planet.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PlanetEvent {
     /// The unique identifier of the event.
     pub id: i32,
     /// The index of the affected planet.
     #[serde(rename = "planetIndex")]
     pub planet_index: i32,
-    /// The identifier indicating the type of the event.
+    /// The type of the event.
     #[serde(rename = "eventType")]
-    pub event_type: i32, // TODO enum type PlanetEventType
-    /// The faction identifier that owns the planet.
-    pub race: i32, // TODO enum type Race
+    pub event_type: PlanetEventType,
+    /// The faction that owns the planet.
+    #[serde(serialize_with = "Faction::serialize_id")]
+    pub race: Faction,
     /// The current health of the event.
     pub health: i64,
     /// The maximum health of the event.
@@ -78,6 +97,25 @@ pub struct PlanetEvent {
     /// A list of identifiers for related joint operations.
     #[serde(rename = "jointOperationIds")]
     pub joint_operations: Vec<JointOperationId>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Represents a galactic effect active on a planet, e.g. caused by a global event.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PlanetActiveEffect {
+    /// The index of the affected planet.
+    pub index: i32,
+    /// The identifier of the active effect.
+    #[serde(rename = "galacticEffectId")]
+    pub galactic_effect_id: i32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Represents the coordinates returned by the ArrowHead API.
@@ -85,7 +123,7 @@ pub type PlanetCoordinates = common::planet::Position;
 
 /// Represents information of a planet.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PlanetInfo {
     /// The identifier for the planet.
     pub index: i32,
@@ -103,7 +141,11 @@ pub struct PlanetInfo {
     pub max_health: i64,
     /// Whether this planet is currently active in the galactic war.
     pub disabled: bool,
-    /// The identifier of the faction that initially owned the planet.
-    #[serde(rename = "initialOwner")]
-    pub initial_owner: i32,
+    /// The faction that initially owned the planet.
+    #[serde(rename = "initialOwner", serialize_with = "Faction::serialize_id")]
+    pub initial_owner: Faction,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
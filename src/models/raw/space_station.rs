@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    middleware,
+    models::common::{space_station::Cost, Extra},
+    prelude::{Parseable, Result},
+    HellApi,
+};
+
+use super::war::WarId;
+
+/// Represents a space station orbiting a planet, e.g. the Democracy Space Station.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SpaceStation {
+    /// An internal identifier of this space station.
+    pub id32: i64,
+    /// The index of the planet the station orbits.
+    #[serde(rename = "planetIndex")]
+    pub planet_index: i32,
+    /// The identifier of the last election deciding the station's destination.
+    #[serde(rename = "lastElectionId")]
+    pub last_election_id: String,
+    /// The identifier of the running election.
+    #[serde(rename = "currentElectionId")]
+    pub current_election_id: String,
+    /// The identifier of the next election.
+    #[serde(rename = "nextElectionId")]
+    pub next_election_id: String,
+    /// The war time at which the running election ends.
+    #[serde(rename = "currentElectionEndWarTime")]
+    pub current_election_end_war_time: u64,
+    /// Flags of the station. Their purpose is unknown.
+    pub flags: i32,
+    /// The tactical actions the station can perform. Not included in the war status.
+    #[serde(
+        rename = "tacticalActions",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub tactical_actions: Vec<TacticalAction>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Parseable for SpaceStation {}
+
+/// Represents an action of a space station unlocked by donating resources.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TacticalAction {
+    /// An internal identifier of this action.
+    pub id32: i64,
+    /// The identifier of the media shown with the action.
+    #[serde(rename = "mediaId32")]
+    pub media_id32: i64,
+    /// The name of the action.
+    pub name: String,
+    /// A description of the action.
+    pub description: String,
+    /// A description of the strategic effects of the action.
+    #[serde(rename = "strategicDescription")]
+    pub strategic_description: String,
+    /// The current status of the action. Its values are not known yet.
+    pub status: i32,
+    /// The war time at which the current status ends.
+    #[serde(rename = "statusExpireAtWarTimeSeconds")]
+    pub status_expire_at_war_time: u64,
+    /// The resources needed to activate the action.
+    pub cost: Vec<Cost>,
+    /// The identifiers of the effects the action applies.
+    #[serde(rename = "effectIds")]
+    pub effect_ids: Vec<i32>,
+    /// The identifiers of the effects currently applied by the action.
+    #[serde(rename = "activeEffectIds")]
+    pub active_effect_ids: Vec<i32>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl HellApi {
+    /// Retrieves the space station with identifier `id32` including its tactical actions.
+    ///
+    /// Endpoint: `/raw/api/v2/SpaceStation/War/{war_id}/{id32}`.
+    pub async fn space_station_raw(&self, war_id: &WarId, id32: i64) -> Result<SpaceStation> {
+        let endpoint = format!("/raw/api/v2/SpaceStation/War/{}/{id32}", war_id.id);
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::space_station_raw()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/v2/SpaceStation/War/{war_id}/{id32}`.
+    pub async fn try_space_station_raw(&self, war_id: &WarId, id32: i64) -> Result<SpaceStation> {
+        let endpoint = format!("/raw/api/v2/SpaceStation/War/{}/{id32}", war_id.id);
+        middleware::request(self, endpoint.as_str()).await
+    }
+}
@@ -1,12 +1,14 @@
 use std::time::Duration;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::DurationSeconds;
 
+use crate::models::common::Extra;
+
 /// Galaxy wide statistics aggregated from all planets.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GalaxyStats {
     /// The amount of missions won.
     #[serde(rename = "missionsWon")]
@@ -49,12 +51,16 @@ pub struct GalaxyStats {
     /// A percentage 0-100 indicating the average accuracy of Helldivers.
     #[serde(rename = "accurracy")] // typo in the API
     pub accuracy: u64,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Represents planet specific statistics.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PlanetStats {
     /// The index for which planet these stats are.
     #[serde(rename = "planetIndex")]
@@ -100,4 +106,8 @@ pub struct PlanetStats {
     /// A percentage 0-100 indicating the average accuracy of Helldivers.
     #[serde(rename = "accurracy")] // typo in the API
     pub accuracy: u64,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
@@ -1,23 +1,25 @@
 use chrono::NaiveDateTime;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::TimestampSeconds;
 
 use crate::{
     middleware,
+    models::common::{faction::Faction, Extra},
     prelude::{Parseable, Result},
     HellApi,
 };
 
 use super::{
     campaign::Campaign,
-    planet::{HomeWorld, PlanetAttack, PlanetEvent, PlanetInfo, PlanetStatus},
+    planet::{HomeWorld, PlanetActiveEffect, PlanetAttack, PlanetEvent, PlanetInfo, PlanetStatus},
+    space_station::SpaceStation,
     stats::{GalaxyStats, PlanetStats},
 };
 
 /// Type of the ID returned from the WarID endpoint.
 #[non_exhaustive]
 #[repr(transparent)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WarId {
     pub id: i32,
 }
@@ -33,7 +35,7 @@ impl Parseable for WarId {}
 /// Represents a snapshot of the current status of the
 /// galactic war.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WarStatus {
     /// The war season this status refers to.
     #[serde(rename = "warId")]
@@ -62,6 +64,33 @@ pub struct WarStatus {
     /// A list of current planet events.
     #[serde(rename = "planetEvents")]
     pub planet_events: Vec<PlanetEvent>,
+    /// A list of effects currently active on planets.
+    #[serde(rename = "planetActiveEffects", default)]
+    pub planet_active_effects: Vec<PlanetActiveEffect>,
+    /// A list of effects of the policies of the current elections. Not typed yet, as it is
+    /// empty in all recorded responses.
+    #[serde(rename = "activeElectionPolicyEffects", default)]
+    pub active_election_policy_effects: Vec<serde_json::Value>,
+    /// A list of current in-game announcements and their effects.
+    #[serde(rename = "globalEvents", default)]
+    pub global_events: Vec<GlobalEvent>,
+    /// A list of community targets. Not typed yet, as it is empty in all recorded responses.
+    #[serde(rename = "communityTargets", default)]
+    pub community_targets: Vec<serde_json::Value>,
+    /// A list of results of earlier wars of Super Earth. Not typed yet, as it is empty in all
+    /// recorded responses.
+    #[serde(rename = "superEarthWarResults", default)]
+    pub super_earth_war_results: Vec<serde_json::Value>,
+    /// A list of space stations in the galaxy.
+    #[serde(rename = "spaceStations", default)]
+    pub space_stations: Vec<SpaceStation>,
+    /// The version of the galaxy layout.
+    #[serde(rename = "layoutVersion", default)]
+    pub layout_version: i32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for WarStatus {}
@@ -69,7 +98,7 @@ impl Parseable for WarStatus {}
 /// Represents information about the current war.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WarInfo {
     /// The war season this WarInfo refers to.
     #[serde(rename = "warId")]
@@ -92,63 +121,190 @@ pub struct WarInfo {
     /// A list of homeworlds for the races (factions) invld in the war.
     #[serde(rename = "homeWorlds")]
     pub home_worlds: Vec<HomeWorld>,
+    /// A list of capital planets. Not typed yet, as it is empty in all recorded responses.
+    #[serde(rename = "capitalInfos", default)]
+    pub capital_infos: Vec<serde_json::Value>,
+    /// A list of effects permanently applied to planets. Not typed yet, as it is empty in all
+    /// recorded responses.
+    #[serde(rename = "planetPermanentEffects", default)]
+    pub planet_permanent_effects: Vec<serde_json::Value>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for WarInfo {}
 
 /// Represents general statistics about the galaxy and specific planets.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WarSummary {
     /// Galaxy wide statistics aggregated from all planets.
     pub galaxy_stats: GalaxyStats,
     // /// Statistics for specific planets.
     #[serde(rename = "planets_stats")]
     pub planet_stats: Vec<PlanetStats>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for WarSummary {}
 
 /// Represents a joint operation.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct JointOperation {
     pub id: i32,
     #[serde(rename = "planetIndex")]
     pub planet_index: i32,
     #[serde(rename = "hqNodeIndex")]
     pub hq_node_index: i32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Represents an in-game announcement that may attach effects to planets.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GlobalEvent {
+    /// The identifier of the event.
+    #[serde(rename = "eventId")]
+    pub event_id: i32,
+    /// An internal identifier of this event.
+    pub id32: i64,
+    /// The internal identifier of the portrait shown with the event.
+    #[serde(rename = "portraitId32")]
+    pub portrait_id32: i64,
+    /// The title of the event in the language set with
+    /// [`HellApiBuilder::language()`](crate::HellApiBuilder::language).
+    pub title: String,
+    /// The internal identifier of the title.
+    #[serde(rename = "titleId32")]
+    pub title_id32: i64,
+    /// The message of the event in the language set with
+    /// [`HellApiBuilder::language()`](crate::HellApiBuilder::language).
+    pub message: String,
+    /// The internal identifier of the message.
+    #[serde(rename = "messageId32")]
+    pub message_id32: i64,
+    /// The faction the event refers to.
+    #[serde(serialize_with = "Faction::serialize_id")]
+    pub race: Faction,
+    /// Flags of the event. Their purpose is unknown.
+    pub flag: i32,
+    /// The internal identifier of the related assignment, `0` if there is none.
+    #[serde(rename = "assignmentId32")]
+    pub assignment_id32: i64,
+    /// The identifiers of the effects the event applies.
+    #[serde(rename = "effectIds")]
+    pub effect_ids: Vec<i32>,
+    /// The indices of the planets the effects apply to.
+    #[serde(rename = "planetIndices")]
+    pub planet_indices: Vec<i32>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl GlobalEvent {
+    /// Returns the internal identifier of the related assignment, if there is one.
+    pub fn assignment(&self) -> Option<i64> {
+        Some(self.assignment_id32).filter(|id| *id != 0)
+    }
+
+    /// Returns whether the effects of the event apply to the planet with index `planet`.
+    pub fn affects(&self, planet: i32) -> bool {
+        self.planet_indices.contains(&planet)
+    }
 }
 
 impl HellApi {
     /// Requests the current war id.
     ///
     /// Endpoint: `/raw/api/WarSeason/current/WarID`.
-    pub async fn war_id() -> Result<WarId> {
-        middleware::request_blocking("/raw/api/WarSeason/current/WarID").await
+    pub async fn war_id(&self) -> Result<WarId> {
+        middleware::request_blocking(self, "/raw/api/WarSeason/current/WarID").await
+    }
+
+    /// Like [`HellApi::war_id()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/WarSeason/current/WarID`.
+    pub async fn try_war_id(&self) -> Result<WarId> {
+        middleware::request(self, "/raw/api/WarSeason/current/WarID").await
     }
 
     /// Requests the current war status.
     ///
     /// Endpoint: `/raw/api/WarSeason/{war_id}/Status`.
-    pub async fn war_status(war_id: &WarId) -> Result<WarStatus> {
+    pub async fn war_status(&self, war_id: &WarId) -> Result<WarStatus> {
         let endpoint = format!("/raw/api/WarSeason/{}/Status", war_id.id);
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::war_status()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/WarSeason/{war_id}/Status`.
+    pub async fn try_war_status(&self, war_id: &WarId) -> Result<WarStatus> {
+        let endpoint = format!("/raw/api/WarSeason/{}/Status", war_id.id);
+        middleware::request(self, endpoint.as_str()).await
+    }
+
+    /// Requests the global events of the war with `war_id`, i.e. the in-game announcements and
+    /// the effects they attach to planets. They are part of the war status, so prefer
+    /// [`HellApi::war_status()`] if it is needed anyway.
+    ///
+    /// Endpoint: `/raw/api/WarSeason/{war_id}/Status`.
+    pub async fn global_events(&self, war_id: &WarId) -> Result<Vec<GlobalEvent>> {
+        Ok(self.war_status(war_id).await?.global_events)
+    }
+
+    /// Like [`HellApi::global_events()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/WarSeason/{war_id}/Status`.
+    pub async fn try_global_events(&self, war_id: &WarId) -> Result<Vec<GlobalEvent>> {
+        Ok(self.try_war_status(war_id).await?.global_events)
     }
 
     /// Requests the current war info.
     ///
     /// Endpoint: `/raw/api/WarSeason/{war_id}/WarInfo`.
-    pub async fn war_info(war_id: &WarId) -> Result<WarInfo> {
+    pub async fn war_info(&self, war_id: &WarId) -> Result<WarInfo> {
         let endpoint = format!("/raw/api/WarSeason/{}/WarInfo", war_id.id);
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::war_info()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/WarSeason/{war_id}/WarInfo`.
+    pub async fn try_war_info(&self, war_id: &WarId) -> Result<WarInfo> {
+        let endpoint = format!("/raw/api/WarSeason/{}/WarInfo", war_id.id);
+        middleware::request(self, endpoint.as_str()).await
     }
 
     /// Requests the current war summary.
     ///
     /// Endpoint: `/raw/api/Stats/war/{war_id}/summary`.
-    pub async fn war_summary(war_id: &WarId) -> Result<WarSummary> {
+    pub async fn war_summary(&self, war_id: &WarId) -> Result<WarSummary> {
+        let endpoint = format!("/raw/api/Stats/war/{}/summary", war_id.id);
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::war_summary()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/raw/api/Stats/war/{war_id}/summary`.
+    pub async fn try_war_summary(&self, war_id: &WarId) -> Result<WarSummary> {
         let endpoint = format!("/raw/api/Stats/war/{}/summary", war_id.id);
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request(self, endpoint.as_str()).await
     }
 }
@@ -1,9 +1,13 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     middleware,
-    models::common,
+    models::common::{
+        self,
+        assignment::{self as tasks, TaskProgress},
+        Extra,
+    },
     prelude::{Parseable, Result},
     HellApi,
 };
@@ -12,11 +16,11 @@ use super::dispatch::Message;
 
 /// Represents an assignment ("Major Order") given by Super Earth to the community.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Assignment {
     /// The unique identifier of the assignment.
     pub id: i64,
-    /// A list of numbers. How they represent progress is currently unknown.
+    /// The progress of each task in the same order. See [`Assignment::task_progress()`].
     pub progress: Vec<i32>,
     /// The title of the assignment.
     pub title: Message,
@@ -30,7 +34,25 @@ pub struct Assignment {
     /// The reward for completing the assignment.
     pub reward: Reward,
     /// The date when the assignment will expire.
+    #[serde(serialize_with = "common::serialize_time")]
     pub expiration: DateTime<Utc>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Assignment {
+    /// Returns the progress of each task, e.g. to show "3/5 planets liberated".
+    pub fn task_progress(&self) -> Vec<TaskProgress<'_>> {
+        tasks::task_progress(&self.tasks, &self.progress)
+    }
+
+    /// Returns the completion of the whole assignment between `0.0` and `1.0`, the mean of
+    /// the completion of its tasks.
+    pub fn completion(&self) -> f64 {
+        tasks::completion(&self.task_progress())
+    }
 }
 
 impl Parseable for Assignment {}
@@ -39,31 +61,55 @@ impl Parseable for Vec<Assignment> {}
 /// Represents a task of an `Assignment`.
 pub type Task = common::assignment::Task;
 
+/// Represents the type of a `Reward`.
+pub type RewardType = common::assignment::RewardType;
+
 /// Represents the reward of an `Assignment`.
 #[non_exhaustive]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Reward {
     /// The type of reward (medals, super credits, etc.).
     #[serde(rename = "type")]
-    pub reward_type: i32,
+    pub reward_type: RewardType,
     /// The amount the player will receive upon completion.
     pub amount: i32,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl HellApi {
     /// Requests current major orders.
     ///
     /// Endpoint: `/api/v1/assignments`.
-    pub async fn assignments() -> Result<Vec<Assignment>> {
-        middleware::request_blocking::<Vec<Assignment>>("/api/v1/assignments").await
+    pub async fn assignments(&self) -> Result<Vec<Assignment>> {
+        middleware::request_blocking(self, "/api/v1/assignments").await
+    }
+
+    /// Like [`HellApi::assignments()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/assignments`.
+    pub async fn try_assignments(&self) -> Result<Vec<Assignment>> {
+        middleware::request(self, "/api/v1/assignments").await
     }
 
     /// Requests a specific major order.
     ///
     /// Endpoint: `/api/v1/assignments/{index}`
-    pub async fn assignment(index: i64) -> Result<Assignment> {
+    pub async fn assignment(&self, index: i64) -> Result<Assignment> {
+        let endpoint = format!("/api/v1/assignments/{index}");
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::assignment()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/assignments/{index}`
+    pub async fn try_assignment(&self, index: i64) -> Result<Assignment> {
         let endpoint = format!("/api/v1/assignments/{index}");
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request(self, endpoint.as_str()).await
     }
 }
 
@@ -161,9 +207,8 @@ mod tests {
                     },
                     "expiration": "2024-06-22T15:54:52.2224108Z"
                   }"#;
-        let json = serde_json::from_str(json).unwrap();
 
-        let assignment = Assignment::parse(json).unwrap();
+        let assignment = Assignment::parse(json.as_bytes()).unwrap();
         for task in assignment.tasks {
             assert_eq!(task.task_type, TaskType::Liberation);
         }
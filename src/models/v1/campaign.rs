@@ -1,7 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     middleware,
+    models::common::{campaign::CampaignType, Extra},
     prelude::{Parseable, Result},
     HellApi,
 };
@@ -10,17 +11,37 @@ use super::planet::Planet;
 
 /// Represents an ongoing campaign on a planet.
 #[non_exhaustive]
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct Campaign {
     /// The unique identifier of this campaign.
     pub id: i32,
     /// The planet on which the campaign takes place.
     pub planet: Planet,
-    /// The type of campaign. TODO This should be mapped onto an enum.
+    /// The type of campaign.
     #[serde(rename = "type")]
-    pub _type: i32,
+    pub _type: CampaignType,
     /// Indicates how many campaigns have already been fought on this planet.
     pub count: u64,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Campaign {
+    /// Returns whether this campaign defends its planet against an attack, which the
+    /// [`CampaignType`] does not tell.
+    pub fn is_defense(&self) -> bool {
+        self.planet
+            .event
+            .as_ref()
+            .is_some_and(|event| event.event_type.is_defense())
+    }
+
+    /// Returns whether this campaign liberates its planet from another faction.
+    pub fn is_liberation(&self) -> bool {
+        self._type == CampaignType::Liberation && !self.is_defense()
+    }
 }
 
 impl Parseable for Campaign {}
@@ -30,16 +51,33 @@ impl HellApi {
     /// Retrieves a list of all available campaign information.
     ///
     /// Endpoint: `/api/v1/campaigns`.
-    pub async fn campaigns() -> Result<Vec<Campaign>> {
-        middleware::request_blocking("/api/v1/campaigns").await
+    pub async fn campaigns(&self) -> Result<Vec<Campaign>> {
+        middleware::request_blocking(self, "/api/v1/campaigns").await
+    }
+
+    /// Like [`HellApi::campaigns()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/campaigns`.
+    pub async fn try_campaigns(&self) -> Result<Vec<Campaign>> {
+        middleware::request(self, "/api/v1/campaigns").await
     }
 
     /// Retrieves a specific campaign with identifier `id`.
     ///
     /// Endpoint: `/api/v1/campaigns/{id}`.
-    pub async fn campaign(id: i32) -> Result<Campaign> {
+    pub async fn campaign(&self, id: i32) -> Result<Campaign> {
+        let endpoint = format!("/api/v1/campaigns/{id}");
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::campaign()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/campaigns/{id}`.
+    pub async fn try_campaign(&self, id: i32) -> Result<Campaign> {
         let endpoint = format!("/api/v1/campaigns/{id}");
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request(self, endpoint.as_str()).await
     }
 }
 
@@ -48,7 +86,7 @@ mod tests {
     use const_format::formatcp;
 
     use crate::{
-        models::v1::planet::Planet,
+        models::{common::campaign::CampaignType, v1::planet::Planet},
         prelude::{Parseable, TestValue},
     };
 
@@ -59,8 +97,9 @@ mod tests {
             Campaign {
                 id: 0,
                 planet: Planet::test_expected(),
-                _type: 1,
+                _type: CampaignType::Unknown(1),
                 count: 2,
+                extra: Default::default(),
             }
         }
 
@@ -77,9 +116,37 @@ mod tests {
 
     #[test]
     fn parse_campaign() {
-        let json = serde_json::from_str(Campaign::TEST_JSON).unwrap();
-        let campaign = Campaign::parse(json).unwrap();
+        let campaign = Campaign::parse(Campaign::TEST_JSON.as_bytes()).unwrap();
 
         assert_eq!(campaign, Campaign::test_expected());
     }
+
+    #[test]
+    fn classify_campaign() {
+        let mut campaign = Campaign::test_expected();
+        assert!(campaign.is_defense());
+        assert!(!campaign.is_liberation());
+
+        campaign.planet.event = None;
+        assert!(!campaign.is_defense());
+        assert!(!campaign.is_liberation());
+    }
+
+    #[test]
+    fn classify_liberation_campaign() {
+        let json = Campaign::TEST_JSON.replace(r#""type": 1"#, r#""type": 0"#);
+        let mut campaign = Campaign::parse(json.as_bytes()).unwrap();
+
+        assert_eq!(campaign._type, CampaignType::Liberation);
+        assert!(campaign.is_defense());
+        assert!(!campaign.is_liberation());
+
+        campaign.planet.event = None;
+        assert!(campaign.is_liberation());
+    }
+
+    #[test]
+    fn round_trip_campaign() {
+        Campaign::assert_round_trip();
+    }
 }
@@ -1,18 +1,19 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
-use serde::Deserialize;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     middleware,
+    models::common::{self, Extra},
     prelude::{Parseable, Result},
     HellApi,
 };
 
 /// A message than can either be a simple String or a LocalizedMessage.
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum Message {
     Simple(String),
-    Localized(LocalizedMessage),
+    Localized(Box<LocalizedMessage>),
 }
 
 impl From<&str> for Message {
@@ -23,54 +24,62 @@ impl From<&str> for Message {
 
 /// A localized message for a specific language.
 #[non_exhaustive]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 #[allow(non_snake_case)]
 pub struct LocalizedMessage {
     /// The message in en-US.
-    #[serde(rename = "en-US")]
+    #[serde(rename = "en-US", skip_serializing_if = "Option::is_none")]
     pub en_US: Option<String>,
     /// The message in de-DE.
-    #[serde(rename = "de-DE")]
+    #[serde(rename = "de-DE", skip_serializing_if = "Option::is_none")]
     pub de_DE: Option<String>,
     /// The message in es-ES.
-    #[serde(rename = "es-ES")]
+    #[serde(rename = "es-ES", skip_serializing_if = "Option::is_none")]
     pub es_ES: Option<String>,
     /// The message in ru-RU.
-    #[serde(rename = "ru-RU")]
+    #[serde(rename = "ru-RU", skip_serializing_if = "Option::is_none")]
     pub ru_RU: Option<String>,
     /// The message in fr-FR.
-    #[serde(rename = "fr-FR")]
+    #[serde(rename = "fr-FR", skip_serializing_if = "Option::is_none")]
     pub fr_FR: Option<String>,
     /// The message in it-IT.
-    #[serde(rename = "it-IT")]
+    #[serde(rename = "it-IT", skip_serializing_if = "Option::is_none")]
     pub it_IT: Option<String>,
     /// The message in pl-PL.
-    #[serde(rename = "pl-PL")]
+    #[serde(rename = "pl-PL", skip_serializing_if = "Option::is_none")]
     pub pl_PL: Option<String>,
     /// The message in zh-Hans.
-    #[serde(rename = "zh-Hans")]
+    #[serde(rename = "zh-Hans", skip_serializing_if = "Option::is_none")]
     pub zh_Hans: Option<String>,
     /// The message in zh-Hant.
-    #[serde(rename = "zh-Hant")]
+    #[serde(rename = "zh-Hant", skip_serializing_if = "Option::is_none")]
     pub zh_Hant: Option<String>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Represents a message from high command to the players like updates on the
 /// status of the war effort.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Dispatch {
     /// The unique identifier of the dispatch.
     pub id: i32,
     /// The time when the dispatch was published.
-    #[serde_as(as = "DateTime<Utc>")]
+    #[serde_as(as = "common::Time")]
     pub published: NaiveDateTime,
     /// The type of dispatch. Purpose unknown as of now.
     #[serde(rename = "type")]
     pub _type: i32,
     /// The message that this dispatch represents.
     pub message: Message,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for Dispatch {}
@@ -79,7 +88,7 @@ impl Parseable for Vec<Dispatch> {}
 /// Represents a news article from Steam's news feed.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct SteamNews {
     /// The identifier assigned by Steam to this news item.
     pub id: String,
@@ -93,8 +102,12 @@ pub struct SteamNews {
     pub content: String,
     /// When the message was posted.
     #[serde(rename = "publishedAt")]
-    #[serde_as(as = "DateTime<Utc>")]
+    #[serde_as(as = "common::Time")]
     pub published: NaiveDateTime,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for SteamNews {}
@@ -104,31 +117,65 @@ impl HellApi {
     /// Retrieves a list of all available dispatch information.
     ///
     /// Endpoint: `/api/v1/dispatches`.
-    pub async fn dispatches() -> Result<Vec<Dispatch>> {
-        middleware::request_blocking("/api/v1/dispatches").await
+    pub async fn dispatches(&self) -> Result<Vec<Dispatch>> {
+        middleware::request_blocking(self, "/api/v1/dispatches").await
+    }
+
+    /// Like [`HellApi::dispatches()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/dispatches`.
+    pub async fn try_dispatches(&self) -> Result<Vec<Dispatch>> {
+        middleware::request(self, "/api/v1/dispatches").await
     }
 
     /// Retrieves a specific dispatch with identifier `id`.
     ///
     /// Endpoint: `/api/v1/dispatches/{id}`.
-    pub async fn dispatch(id: i32) -> Result<Dispatch> {
+    pub async fn dispatch(&self, id: i32) -> Result<Dispatch> {
+        let endpoint = format!("/api/v1/dispatches/{id}");
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::dispatch()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/dispatches/{id}`.
+    pub async fn try_dispatch(&self, id: i32) -> Result<Dispatch> {
         let endpoint = format!("/api/v1/dispatches/{id}");
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request(self, endpoint.as_str()).await
     }
 
     /// Retrieves the Steam newsfeeed for Helldivers 2.
     ///
     /// Endpoint: `/api/v1/steam`.
-    pub async fn steam_newsfeed() -> Result<Vec<SteamNews>> {
-        middleware::request_blocking("/api/v1/steam").await
+    pub async fn steam_newsfeed(&self) -> Result<Vec<SteamNews>> {
+        middleware::request_blocking(self, "/api/v1/steam").await
+    }
+
+    /// Like [`HellApi::steam_newsfeed()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/steam`.
+    pub async fn try_steam_newsfeed(&self) -> Result<Vec<SteamNews>> {
+        middleware::request(self, "/api/v1/steam").await
     }
 
     /// Retrieves a specific newsfeed item from the Helldivers 2 Steam newsfeed.
     ///
     /// Endpoint: `/api/v1/steam/{gid}`.
-    pub async fn steam_newsitem(gid: &String) -> Result<SteamNews> {
+    pub async fn steam_newsitem(&self, gid: &String) -> Result<SteamNews> {
+        let endpoint = format!("/api/v1/steam/{gid}");
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::steam_newsitem()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/steam/{gid}`.
+    pub async fn try_steam_newsitem(&self, gid: &String) -> Result<SteamNews> {
         let endpoint = format!("/api/v1/steam/{gid}");
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request(self, endpoint.as_str()).await
     }
 }
 
@@ -154,6 +201,7 @@ mod tests {
                 .unwrap(),
                 _type: 1,
                 message: Message::from("string"),
+                extra: Default::default(),
             }
         }
 
@@ -180,6 +228,7 @@ mod tests {
                     "%Y-%m-%dT%H:%M:%S%.fZ",
                 )
                 .unwrap(),
+                extra: Default::default(),
             }
         }
 
@@ -195,17 +244,25 @@ mod tests {
 
     #[test]
     fn parse_dispatch() {
-        let json = serde_json::from_str(Dispatch::TEST_JSON).unwrap();
-        let dispatch = Dispatch::parse(json).unwrap();
+        let dispatch = Dispatch::parse(Dispatch::TEST_JSON.as_bytes()).unwrap();
 
         assert_eq!(dispatch, Dispatch::test_expected());
     }
 
     #[test]
     fn parse_steam_news() {
-        let json = serde_json::from_str(SteamNews::TEST_JSON).unwrap();
-        let steam_news = SteamNews::parse(json).unwrap();
+        let steam_news = SteamNews::parse(SteamNews::TEST_JSON.as_bytes()).unwrap();
 
         assert_eq!(steam_news, SteamNews::test_expected());
     }
+
+    #[test]
+    fn round_trip_dispatch() {
+        Dispatch::assert_round_trip();
+    }
+
+    #[test]
+    fn round_trip_steam_news() {
+        SteamNews::assert_round_trip();
+    }
 }
@@ -2,5 +2,6 @@ pub mod assignment;
 pub mod campaign;
 pub mod dispatch;
 pub mod planet;
+pub mod space_station;
 pub mod stats;
 pub mod war;
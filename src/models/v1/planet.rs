@@ -1,11 +1,14 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
-use serde::Deserialize;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     middleware,
     models::common::{
         self,
         assignment::{CampaignId, JointOperationId},
+        faction::Faction,
+        planet::PlanetEventType,
+        Extra,
     },
     prelude::{Parseable, Result},
     HellApi,
@@ -19,15 +22,15 @@ pub type Position = common::planet::Position;
 /// Represents an ongoing event on a planet.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Event {
     /// The unique identifier of the event.
     pub id: i32,
-    /// The identifier indicating the type of the event.
+    /// The type of the event.
     #[serde(rename = "eventType")]
-    pub event_type: i32, // TODO enum type PlanetEventType
-    /// The faction identifier that owns the planet.
-    pub faction: String,
+    pub event_type: PlanetEventType,
+    /// The faction that owns the planet.
+    pub faction: Faction,
     /// The current health of the event.
     pub health: i64,
     /// The maximum health of the event.
@@ -35,11 +38,11 @@ pub struct Event {
     pub max_health: i64,
     /// The time at which this event starts.
     #[serde(rename = "startTime")]
-    #[serde_as(as = "DateTime<Utc>")]
+    #[serde_as(as = "common::Time")]
     pub start: NaiveDateTime,
     /// The time at which this event ends.
     #[serde(rename = "endTime")]
-    #[serde_as(as = "DateTime<Utc>")]
+    #[serde_as(as = "common::Time")]
     pub end: NaiveDateTime,
     /// The identifier of a related Campagin.
     #[serde(rename = "campaignId")]
@@ -47,11 +50,15 @@ pub struct Event {
     /// A list of identifiers for related joint operations.
     #[serde(rename = "jointOperationIds")]
     pub joint_operations: Vec<JointOperationId>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Contains all aggregated information ArrowHead has about a planet.
 #[non_exhaustive]
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct Planet {
     /// The unique identifier ArrowHead assigned to this planet.
     #[serde(rename = "index")]
@@ -79,10 +86,10 @@ pub struct Planet {
     pub disabled: bool,
     /// The faction that originally owned the planet.
     #[serde(rename = "initialOwner")]
-    pub initial_owner: String,
+    pub initial_owner: Faction,
     /// The faction that currently owns the planet.
     #[serde(rename = "currentOwner")]
-    pub current_owner: String,
+    pub current_owner: Faction,
     /// How much the planet regenerates health when left alone.
     #[serde(rename = "regenPerSecond")]
     pub regen_per_second: f32,
@@ -92,6 +99,10 @@ pub struct Planet {
     pub statistics: Statistics,
     /// A list of planets currently attacked by this planet.
     pub attacking: Vec<i32>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for Planet {}
@@ -99,45 +110,78 @@ impl Parseable for Vec<Planet> {}
 
 /// Represents information about a biome of a Planet.
 #[non_exhaustive]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Biome {
     // The name of the biome.
     pub name: String,
     /// A short human-readable description of the biome.
     pub description: String,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Represents an environmental hazard that can be present on a Planet.
 #[non_exhaustive]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Hazard {
     /// The name of the environmental hazard.
     pub name: String,
     /// The description of the environmental hazard.
     pub description: String,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl HellApi {
     /// Retrieves a list of all available planet information.
     ///
     /// Endpoint: `/api/v1/planets`.
-    pub async fn planets() -> Result<Vec<Planet>> {
-        middleware::request_blocking("/api/v1/planets").await
+    pub async fn planets(&self) -> Result<Vec<Planet>> {
+        middleware::request_blocking(self, "/api/v1/planets").await
+    }
+
+    /// Like [`HellApi::planets()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/planets`.
+    pub async fn try_planets(&self) -> Result<Vec<Planet>> {
+        middleware::request(self, "/api/v1/planets").await
     }
 
     /// Retrieves a specific planet with identifier `id`.
     ///
     /// Endpoint: `/api/v1/planets/{id}`.
-    pub async fn planet(id: i32) -> Result<Planet> {
+    pub async fn planet(&self, id: i32) -> Result<Planet> {
+        let endpoint = format!("/api/v1/planets/{id}");
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::planet()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/planets/{id}`.
+    pub async fn try_planet(&self, id: i32) -> Result<Planet> {
         let endpoint = format!("/api/v1/planets/{id}");
-        middleware::request_blocking(endpoint.as_str()).await
+        middleware::request(self, endpoint.as_str()).await
     }
 
     /// Retrieves a list of all planets with an active event.
     ///
     /// Endpoint: `/api/v1/planet-events`.
-    pub async fn planet_events() -> Result<Vec<Planet>> {
-        middleware::request_blocking("/api/v1/planet-events").await
+    pub async fn planet_events(&self) -> Result<Vec<Planet>> {
+        middleware::request_blocking(self, "/api/v1/planet-events").await
+    }
+
+    /// Like [`HellApi::planet_events()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/planet-events`.
+    pub async fn try_planet_events(&self) -> Result<Vec<Planet>> {
+        middleware::request(self, "/api/v1/planet-events").await
     }
 }
 
@@ -147,7 +191,10 @@ mod tests {
     use const_format::formatcp;
 
     use crate::{
-        models::v1::{dispatch::Message, stats::Statistics},
+        models::{
+            common::{faction::Faction, planet::PlanetEventType},
+            v1::{dispatch::Message, stats::Statistics},
+        },
         prelude::{Parseable, TestValue},
     };
 
@@ -157,8 +204,8 @@ mod tests {
         fn test_expected() -> Self {
             Event {
                 id: 0,
-                event_type: 1,
-                faction: String::from("faction"),
+                event_type: PlanetEventType::Defense,
+                faction: Faction::Terminids,
                 health: 2,
                 max_health: 3,
                 start: NaiveDateTime::parse_from_str(
@@ -173,13 +220,14 @@ mod tests {
                 .unwrap(),
                 campaign_id: 4,
                 joint_operations: vec![5],
+                extra: Default::default(),
             }
         }
 
         const TEST_JSON: &'static str = r#"{
                 "id": 0,
                 "eventType": 1,
-                "faction": "faction",
+                "faction": "Terminids",
                 "health": 2,
                 "maxHealth": 3,
                 "startTime": "2024-07-06T20:49:56.700Z",
@@ -200,20 +248,26 @@ mod tests {
                 biome: Biome {
                     name: String::from("biome"),
                     description: String::from("biome is cold"),
+                    extra: Default::default(),
                 },
                 hazards: vec![],
                 hash: 1,
-                position: Position { x: 2f32, y: 3f32 },
+                position: Position {
+                    x: 2f32,
+                    y: 3f32,
+                    extra: Default::default(),
+                },
                 waypoints: vec![4],
                 max_health: 5,
                 health: 6,
                 disabled: true,
-                initial_owner: String::from("someone"),
-                current_owner: String::from("owner"),
+                initial_owner: Faction::Humans,
+                current_owner: Faction::Automaton,
                 regen_per_second: 7f32,
                 event: Some(Event::test_expected()),
                 statistics: Statistics::test_expected(),
                 attacking: vec![29],
+                extra: Default::default(),
             }
         }
 
@@ -238,8 +292,8 @@ mod tests {
               "maxHealth": 5,
               "health": 6,
               "disabled": true,
-              "initialOwner": "someone",
-              "currentOwner": "owner",
+              "initialOwner": "Humans",
+              "currentOwner": "Automaton",
               "regenPerSecond": 7,
               "event": {},
               "statistics": {},
@@ -254,9 +308,18 @@ mod tests {
 
     #[test]
     fn parse_planet() {
-        let json = serde_json::from_str(Planet::TEST_JSON).unwrap();
-        let planet = Planet::parse(json).unwrap();
+        let planet = Planet::parse(Planet::TEST_JSON.as_bytes()).unwrap();
 
         assert_eq!(planet, Planet::test_expected());
     }
+
+    #[test]
+    fn round_trip_event() {
+        Event::assert_round_trip();
+    }
+
+    #[test]
+    fn round_trip_planet() {
+        Planet::assert_round_trip();
+    }
 }
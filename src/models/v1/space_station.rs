@@ -0,0 +1,194 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    middleware,
+    models::common::{self, space_station::Cost, Extra},
+    prelude::{Parseable, Result},
+    HellApi,
+};
+
+use super::planet::Planet;
+
+/// Represents a space station like the Democracy Space Station.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct SpaceStation {
+    /// The unique identifier of the station.
+    pub id32: i64,
+    /// The planet the station currently orbits.
+    pub planet: Planet,
+    /// The time at which the election of the next planet ends.
+    #[serde(rename = "electionEnd", serialize_with = "common::serialize_time")]
+    pub election_end: DateTime<Utc>,
+    /// Flags of the station. Their purpose is unknown.
+    pub flags: i32,
+    /// The tactical actions the station can perform.
+    #[serde(rename = "tacticalActions")]
+    pub tactical_actions: Vec<TacticalAction>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl Parseable for SpaceStation {}
+impl Parseable for Vec<SpaceStation> {}
+
+/// Represents an action of a space station unlocked by donating resources.
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct TacticalAction {
+    /// The unique identifier of the action.
+    pub id32: i64,
+    /// The identifier of the media shown with the action.
+    #[serde(rename = "mediaId32")]
+    pub media_id32: i64,
+    /// The name of the action.
+    pub name: String,
+    /// A description of the action.
+    pub description: String,
+    /// A description of the strategic effects of the action.
+    #[serde(rename = "strategicDescription")]
+    pub strategic_description: String,
+    /// The current status of the action. Its values are not known yet.
+    pub status: i32,
+    /// The time at which the current status ends.
+    #[serde(rename = "statusExpire", serialize_with = "common::serialize_time")]
+    pub status_expire: DateTime<Utc>,
+    /// The resources needed to activate the action.
+    pub costs: Vec<Cost>,
+    /// The identifiers of the effects the action applies.
+    #[serde(rename = "effectIds")]
+    pub effect_ids: Vec<i32>,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl HellApi {
+    /// Retrieves a list of all space stations.
+    ///
+    /// Endpoint: `/api/v1/space-stations`.
+    pub async fn space_stations(&self) -> Result<Vec<SpaceStation>> {
+        middleware::request_blocking(self, "/api/v1/space-stations").await
+    }
+
+    /// Like [`HellApi::space_stations()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/space-stations`.
+    pub async fn try_space_stations(&self) -> Result<Vec<SpaceStation>> {
+        middleware::request(self, "/api/v1/space-stations").await
+    }
+
+    /// Retrieves a specific space station with identifier `id32`.
+    ///
+    /// Endpoint: `/api/v1/space-stations/{id32}`.
+    pub async fn space_station(&self, id32: i64) -> Result<SpaceStation> {
+        let endpoint = format!("/api/v1/space-stations/{id32}");
+        middleware::request_blocking(self, endpoint.as_str()).await
+    }
+
+    /// Like [`HellApi::space_station()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/space-stations/{id32}`.
+    pub async fn try_space_station(&self, id32: i64) -> Result<SpaceStation> {
+        let endpoint = format!("/api/v1/space-stations/{id32}");
+        middleware::request(self, endpoint.as_str()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use const_format::formatcp;
+
+    use crate::{
+        models::{common::space_station::Cost, v1::planet::Planet},
+        prelude::{Parseable, TestValue},
+    };
+
+    use super::{SpaceStation, TacticalAction};
+
+    impl TestValue for SpaceStation {
+        fn test_expected() -> Self {
+            SpaceStation {
+                id32: 749875195,
+                planet: Planet::test_expected(),
+                election_end: Utc.with_ymd_and_hms(2024, 7, 7, 16, 10, 32).unwrap(),
+                flags: 1,
+                tactical_actions: vec![TacticalAction {
+                    id32: 1238062325,
+                    media_id32: 3946993796,
+                    name: String::from("ORBITAL BLOCKADE"),
+                    description: String::from("Prevents enemy reinforcements."),
+                    strategic_description: String::from("Enemy reinforcements are halted."),
+                    status: 1,
+                    status_expire: Utc.with_ymd_and_hms(2024, 7, 8, 16, 10, 32).unwrap(),
+                    costs: vec![Cost {
+                        id: String::from("3992382197-0"),
+                        item_mix_id: 3992382197,
+                        target_value: 50000.0,
+                        current_value: 20000.0,
+                        delta_per_second: 1.5,
+                        max_donation_amount: 100,
+                        max_donation_period_seconds: 3600,
+                        extra: Default::default(),
+                    }],
+                    effect_ids: vec![1190],
+                    extra: Default::default(),
+                }],
+                extra: Default::default(),
+            }
+        }
+
+        const TEST_JSON: &'static str = formatcp!(
+            r#"{{
+                "id32": 749875195,
+                "planet": {},
+                "electionEnd": "2024-07-07T16:10:32Z",
+                "flags": 1,
+                "tacticalActions": [
+                    {{
+                        "id32": 1238062325,
+                        "mediaId32": 3946993796,
+                        "name": "ORBITAL BLOCKADE",
+                        "description": "Prevents enemy reinforcements.",
+                        "strategicDescription": "Enemy reinforcements are halted.",
+                        "status": 1,
+                        "statusExpire": "2024-07-08T16:10:32Z",
+                        "costs": [
+                            {{
+                                "id": "3992382197-0",
+                                "itemMixId": 3992382197,
+                                "targetValue": 50000,
+                                "currentValue": 20000,
+                                "deltaPerSecond": 1.5,
+                                "maxDonationAmmount": 100,
+                                "maxDonationPeriodSeconds": 3600
+                            }}
+                        ],
+                        "effectIds": [1190]
+                    }}
+                ]
+            }}"#,
+            Planet::TEST_JSON
+        );
+    }
+
+    #[test]
+    fn parse_space_station() {
+        let station = SpaceStation::parse(SpaceStation::TEST_JSON.as_bytes()).unwrap();
+
+        assert_eq!(station, SpaceStation::test_expected());
+        assert_eq!(station.tactical_actions[0].costs[0].fraction(), 0.4);
+    }
+
+    #[test]
+    fn round_trip_space_station() {
+        SpaceStation::assert_round_trip();
+    }
+}
@@ -1,12 +1,14 @@
 use std::time::Duration;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::DurationSeconds;
 
+use crate::models::common::Extra;
+
 /// Represents base statistics.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Statistics {
     /// The amount of missions won.
     #[serde(rename = "missionsWon")]
@@ -51,6 +53,10 @@ pub struct Statistics {
     /// The player count at the time of the snapshot.
     #[serde(rename = "playerCount")]
     pub player_count: u64,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 #[cfg(test)]
@@ -79,6 +85,7 @@ mod tests {
                 mission_success_rate: 91,
                 accuracy: 100,
                 player_count: 728,
+                extra: Default::default(),
             }
         }
 
@@ -100,4 +107,9 @@ mod tests {
                 "playerCount": 728
               }"#;
     }
+
+    #[test]
+    fn round_trip_statistics() {
+        Statistics::assert_round_trip();
+    }
 }
@@ -1,8 +1,9 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
-use serde::Deserialize;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     middleware,
+    models::common::{self, faction::Faction, Extra},
     prelude::{Parseable, Result},
     HellApi,
 };
@@ -12,27 +13,31 @@ use super::stats::Statistics;
 /// Global information about the ongoing war.
 #[non_exhaustive]
 #[serde_with::serde_as]
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct War {
     /// When this war was started as a datetime String
-    #[serde_as(as = "DateTime<Utc>")]
+    #[serde_as(as = "common::Time")]
     pub started: NaiveDateTime,
     /// When the war will end (or has ended) as a datetime String,
-    #[serde_as(as = "DateTime<Utc>")]
+    #[serde_as(as = "common::Time")]
     pub ended: NaiveDateTime,
     /// The time the snapshot of the war was taken.
-    #[serde_as(as = "DateTime<Utc>")]
+    #[serde_as(as = "common::Time")]
     pub now: NaiveDateTime,
     /// The minimum client version required to play in this war.
     #[serde(rename = "clientVersion")]
     pub client_version: String,
-    /// A list of faction names involved in the war
-    pub factions: Vec<String>,
+    /// A list of factions involved in the war
+    pub factions: Vec<Faction>,
     /// A fraction used to calculate the impact of a mission on the war effort.
     #[serde(rename = "impactMultiplier")]
     pub impact_multiplier: f32,
     /// The statistics available for the galaxy wide war.
     pub statistics: Statistics,
+    /// Fields not known to this crate yet, e.g. after a schema change upstream.
+    #[cfg_attr(feature = "extra", serde(flatten))]
+    #[cfg_attr(not(feature = "extra"), serde(skip))]
+    pub extra: Extra,
 }
 
 impl Parseable for War {}
@@ -41,8 +46,16 @@ impl HellApi {
     /// Requests the the current war.
     ///
     /// Endpoint: `/api/v1/war`.
-    pub async fn war() -> Result<War> {
-        middleware::request_blocking("/api/v1/war").await
+    pub async fn war(&self) -> Result<War> {
+        middleware::request_blocking(self, "/api/v1/war").await
+    }
+
+    /// Like [`HellApi::war()`] but fails with
+    /// [`Error::RateLimitReached`](crate::prelude::Error::RateLimitReached) instead of waiting.
+    ///
+    /// Endpoint: `/api/v1/war`.
+    pub async fn try_war(&self) -> Result<War> {
+        middleware::request(self, "/api/v1/war").await
     }
 }
 
@@ -93,15 +106,20 @@ mod tests {
                 factions: vec![],
                 impact_multiplier: 0f32,
                 statistics: Statistics::test_expected(),
+                extra: Default::default(),
             }
         }
     }
 
     #[test]
     fn parse_war() {
-        let json = serde_json::from_str(War::TEST_JSON).unwrap();
-        let war = War::parse(json).unwrap();
+        let war = War::parse(War::TEST_JSON.as_bytes()).unwrap();
 
         assert_eq!(war, War::test_expected());
     }
+
+    #[test]
+    fn round_trip_war() {
+        War::assert_round_trip();
+    }
 }
@@ -1,8 +1,7 @@
-use lazy_static::lazy_static;
-use serde::de::DeserializeOwned;
-use serde_json::Value;
+use std::{error::Error as StdError, io, sync::Arc};
 
-use crate::middleware::RateLimit;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -11,12 +10,259 @@ pub enum Error {
     /// HTTP request failed to complete successfully.
     #[error("HTTP request failed. {0:?}")]
     RequestError(#[from] reqwest::Error),
+    /// The requested resource does not exist, e.g. a planet index that is out of range.
+    #[error("Nothing found at {endpoint}.")]
+    NotFound {
+        /// The requested endpoint.
+        endpoint: String,
+    },
+    /// The API answered with an unsuccessful HTTP status not covered by another variant.
+    #[error("Request to {endpoint} failed with HTTP status {status}. {body_snippet}")]
+    HttpStatus {
+        /// The requested endpoint.
+        endpoint: String,
+        /// The status returned by the API.
+        status: StatusCode,
+        /// The beginning of the response body.
+        body_snippet: String,
+    },
+    /// The API or a gateway in front of it is currently down (`502`/`503`).
+    #[error("Service unavailable at {endpoint} (HTTP status {status}).")]
+    ServiceUnavailable {
+        /// The requested endpoint.
+        endpoint: String,
+        /// The status returned by the API.
+        status: StatusCode,
+    },
+    /// The request timed out, either locally or at a gateway (`504`).
+    #[error("Request to {endpoint} timed out.")]
+    Timeout {
+        /// The requested endpoint.
+        endpoint: String,
+    },
+    /// The request still failed after retrying it. The last error need not be
+    /// [retryable](Error::is_retryable), e.g. a `404` after a `503`.
+    #[error("Request failed after {attempts} attempts. {source}")]
+    Retry {
+        /// How many attempts were made.
+        attempts: u32,
+        /// The error of the last attempt.
+        source: Box<Error>,
+    },
+    /// The request was sent once for several concurrent callers and failed. Only the callers
+    /// waiting for another caller's request receive this, the caller that sent it receives the
+    /// error itself.
+    #[error("{0}")]
+    Shared(Arc<Error>),
     /// The Rate-Limit has been reached.
     #[error("Rate limit reached.")]
     RateLimitReached(core::time::Duration),
     /// Parsing of JSON response failed.
-    #[error("Parsing of JSON failed. {0}")]
-    ParseError(#[from] serde_json::Error),
+    #[error("Parsing of JSON{} failed. {source}", parse_context(.endpoint, .path))]
+    ParseError {
+        /// The requested endpoint, if the JSON is a response of the API.
+        endpoint: Option<String>,
+        /// The path of the offending value, e.g. `[57].event.jointOperationIds[0]`. Empty if
+        /// unknown.
+        path: String,
+        /// The underlying error.
+        source: serde_json::Error,
+        /// The complete response body, if enabled using
+        /// [`HellApiBuilder::dump_payloads()`](crate::HellApiBuilder::dump_payloads).
+        payload: Option<String>,
+    },
+    /// A custom [`Transport`](crate::transport::Transport) failed to obtain a response.
+    #[error("Transport failed. {0}")]
+    TransportError(Box<dyn std::error::Error + Send + Sync>),
+    /// Reading or writing a file failed.
+    #[error("I/O operation failed. {0}")]
+    IoError(#[from] std::io::Error),
+    /// A configured header value is not a valid HTTP header value.
+    #[error("Invalid header value. {0}")]
+    InvalidHeader(#[from] reqwest::header::InvalidHeaderValue),
+}
+
+impl Error {
+    /// Returns whether the error is transient and the request may succeed when sent again.
+    ///
+    /// Rate limits (`429`), an unavailable API (`502`/`503`), timeouts (`504`) and dropped
+    /// connections are retryable. Other HTTP errors, parse errors and configuration errors are
+    /// not.
+    ///
+    /// A request failing after more than one attempt returns [`Error::Retry`] with the amount
+    /// of attempts, also if its last error is not retryable, e.g. a `404` after a `503`.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::RateLimitReached(_)
+            | Error::ServiceUnavailable { .. }
+            | Error::Timeout { .. } => true,
+            Error::HttpStatus { status, .. } => matches!(
+                *status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            Error::RequestError(e) => e.is_connect() || e.is_timeout() || has_transient_io(e),
+            Error::IoError(e) => is_transient_io(e),
+            Error::TransportError(e) => {
+                e.downcast_ref::<Duplicate>().is_some_and(|e| e.retryable)
+                    || has_transient_io(e.as_ref())
+            }
+            Error::Retry { source, .. } => source.is_retryable(),
+            Error::Shared(error) => error.is_retryable(),
+            _ => false,
+        }
+    }
+
+    /// Returns the endpoint the failed request was sent to, if known.
+    pub fn endpoint(&self) -> Option<&str> {
+        match self {
+            Error::NotFound { endpoint }
+            | Error::HttpStatus { endpoint, .. }
+            | Error::ServiceUnavailable { endpoint, .. }
+            | Error::Timeout { endpoint } => Some(endpoint),
+            Error::ParseError { endpoint, .. } => endpoint.as_deref(),
+            Error::Retry { source, .. } => source.endpoint(),
+            Error::Shared(error) => error.endpoint(),
+            _ => None,
+        }
+    }
+
+    /// Returns the error that caused this one, unwrapping [`Error::Retry`] and
+    /// [`Error::Shared`]. Any other error is returned as it is.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Retry { source, .. } => source.root(),
+            Error::Shared(error) => error.root(),
+            error => error,
+        }
+    }
+
+    /// Returns a copy of this error to share with other callers.
+    ///
+    /// Sources that cannot be cloned are replaced: I/O errors keep their kind, parse errors
+    /// their message and any other source becomes a [`Error::TransportError`] with the same
+    /// message and [retryability](Error::is_retryable).
+    pub(crate) fn duplicate(&self) -> Error {
+        match self {
+            Error::NotFound { endpoint } => Error::NotFound {
+                endpoint: endpoint.clone(),
+            },
+            Error::HttpStatus {
+                endpoint,
+                status,
+                body_snippet,
+            } => Error::HttpStatus {
+                endpoint: endpoint.clone(),
+                status: *status,
+                body_snippet: body_snippet.clone(),
+            },
+            Error::ServiceUnavailable { endpoint, status } => Error::ServiceUnavailable {
+                endpoint: endpoint.clone(),
+                status: *status,
+            },
+            Error::Timeout { endpoint } => Error::Timeout {
+                endpoint: endpoint.clone(),
+            },
+            Error::Retry { attempts, source } => Error::Retry {
+                attempts: *attempts,
+                source: Box::new(source.duplicate()),
+            },
+            Error::Shared(error) => Error::Shared(error.clone()),
+            Error::RateLimitReached(wait) => Error::RateLimitReached(*wait),
+            Error::ParseError {
+                endpoint,
+                path,
+                source,
+                payload,
+            } => Error::ParseError {
+                endpoint: endpoint.clone(),
+                path: path.clone(),
+                source: serde::de::Error::custom(source),
+                payload: payload.clone(),
+            },
+            Error::IoError(e) => Error::IoError(io::Error::new(e.kind(), e.to_string())),
+            Error::RequestError(_) | Error::TransportError(_) | Error::InvalidHeader(_) => {
+                Error::TransportError(Box::new(Duplicate {
+                    message: self.to_string(),
+                    retryable: self.is_retryable(),
+                }))
+            }
+        }
+    }
+}
+
+/// Stands in for an error source that cannot be cloned, see [`Error::duplicate()`].
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+struct Duplicate {
+    /// The message of the original error.
+    message: String,
+    /// Whether the original error was retryable.
+    retryable: bool,
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(source: serde_json::Error) -> Self {
+        Error::ParseError {
+            endpoint: None,
+            path: String::new(),
+            source,
+            payload: None,
+        }
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for Error {
+    fn from(error: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let path = error.path().to_string();
+        Error::ParseError {
+            endpoint: None,
+            path,
+            source: error.into_inner(),
+            payload: None,
+        }
+    }
+}
+
+/// Describes where the JSON of a [`Error::ParseError`] came from.
+fn parse_context(endpoint: &Option<String>, path: &str) -> String {
+    let mut context = String::new();
+    if let Some(endpoint) = endpoint {
+        context += &format!(" from {endpoint}");
+    }
+    if !path.is_empty() {
+        context += &format!(" at `{path}`");
+    }
+    context
+}
+
+/// Returns whether `error` or one of its sources is a transient I/O error.
+fn has_transient_io(error: &(dyn StdError + 'static)) -> bool {
+    let mut current = Some(error);
+    while let Some(error) = current {
+        if error
+            .downcast_ref::<io::Error>()
+            .is_some_and(is_transient_io)
+        {
+            return true;
+        }
+        current = error.source();
+    }
+    false
+}
+
+/// Returns whether `error` indicates a dropped or timed out connection.
+fn is_transient_io(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::TimedOut
+    )
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -26,11 +272,22 @@ pub(crate) struct W<T>(pub T);
 
 /// Specifies that the object is JSON parseable and provides
 /// the `parse()` method to return a struct of type `T`.
-pub(crate) trait Parseable: DeserializeOwned {
-    /// Tries to parse the JSON response from the API endpoint and returns `Ok(T)`
-    /// if parsing succeeded - `Err(HellHubError)` otherwise.
-    fn parse(json: Value) -> Result<Self> {
-        serde_json::from_value::<Self>(json).map_err(Error::ParseError)
+///
+/// This is how the client parses every response body.
+pub trait Parseable: DeserializeOwned + Clone + Send + Sync + 'static {
+    /// Tries to parse the raw JSON response body from the API endpoint and returns `Ok(T)`
+    /// if parsing succeeded - `Err(HellHubError)` otherwise. Errors contain the path of the
+    /// offending value.
+    fn parse(json: &[u8]) -> Result<Self> {
+        // tracking the path allocates for every field, so it is only done to report an error
+        let error = match serde_json::from_slice(json) {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        let mut deserializer = serde_json::Deserializer::from_slice(json);
+        serde_path_to_error::deserialize::<_, Self>(&mut deserializer)?;
+        deserializer.end()?;
+        Err(error.into())
     }
 }
 
@@ -42,8 +299,19 @@ pub(crate) trait TestValue {
 
     /// Returns the raw json string for a test.
     const TEST_JSON: &'static str;
-}
 
-lazy_static! {
-    pub(crate) static ref RATE_LIMIT: RateLimit = RateLimit::default();
+    /// Asserts that both the expected value and the parsed test json stay the same when
+    /// serialized and parsed again.
+    fn assert_round_trip()
+    where
+        Self: Sized + serde::Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+    {
+        let expected = Self::test_expected();
+        let json = serde_json::to_string(&expected).unwrap();
+        assert_eq!(serde_json::from_str::<Self>(&json).unwrap(), expected);
+
+        let parsed = serde_json::from_str::<Self>(Self::TEST_JSON).unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(serde_json::from_str::<Self>(&json).unwrap(), parsed);
+    }
 }
@@ -0,0 +1,164 @@
+//! Configuration of how failed requests are retried.
+
+use std::time::Duration;
+
+use crate::prelude::Error;
+
+/// Decides whether and when a failed request is sent again.
+///
+/// Only errors that are [retryable](Error::is_retryable) are retried, any other error is
+/// returned immediately. Between attempts the client waits for an
+/// exponentially growing delay with random jitter, and additionally for as long as the
+/// rate limit requires.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum amount of attempts including the first one.
+    max_attempts: u32,
+    /// The delay after the first failed attempt.
+    base_delay: Duration,
+    /// The upper bound of the delay between two attempts.
+    max_delay: Duration,
+    /// Whether to randomize the delay.
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making at most `max_attempts` attempts with the default delays.
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// A policy that never retries.
+    pub fn never() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the delay after the first failed attempt. Every further attempt doubles it.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the upper bound of the delay between two attempts.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets whether the delay is randomized. With jitter, the delay is chosen uniformly
+    /// between half and the full exponential delay so concurrent clients spread out.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The maximum amount of attempts including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the delay before the attempt following the failed `attempt` (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay.mul_f64(0.5 + fastrand::f64() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// Returns whether another attempt should be made after `attempt` failed with `error`.
+    pub(crate) fn should_retry(&self, attempt: u32, error: &Error) -> bool {
+        attempt < self.max_attempts && error.is_retryable()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io, time::Duration};
+
+    use reqwest::StatusCode;
+
+    use crate::prelude::Error;
+
+    use super::RetryPolicy;
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let policy = RetryPolicy::new(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .with_jitter(false);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(5), Duration::from_secs(1));
+        assert_eq!(policy.backoff(100), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn backoff_jitter_stays_in_bounds() {
+        let policy = RetryPolicy::new(10).with_base_delay(Duration::from_millis(100));
+
+        for _ in 0..100 {
+            let delay = policy.backoff(2);
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn only_transient_errors_are_retried() {
+        let policy = RetryPolicy::new(3);
+        let endpoint = String::from("/api/v1/war");
+        let status = |status| Error::HttpStatus {
+            endpoint: endpoint.clone(),
+            status,
+            body_snippet: String::new(),
+        };
+        let unavailable = Error::ServiceUnavailable {
+            endpoint: endpoint.clone(),
+            status: StatusCode::SERVICE_UNAVAILABLE,
+        };
+
+        assert!(policy.should_retry(1, &status(StatusCode::TOO_MANY_REQUESTS)));
+        assert!(policy.should_retry(2, &unavailable));
+        assert!(policy.should_retry(
+            1,
+            &Error::Timeout {
+                endpoint: endpoint.clone()
+            }
+        ));
+        assert!(policy.should_retry(1, &io::Error::from(io::ErrorKind::ConnectionReset).into()));
+        assert!(!policy.should_retry(3, &unavailable));
+        assert!(!policy.should_retry(
+            1,
+            &Error::NotFound {
+                endpoint: endpoint.clone()
+            }
+        ));
+        assert!(!policy.should_retry(1, &status(StatusCode::INTERNAL_SERVER_ERROR)));
+        let parse_error = serde_json::from_str::<u32>("null").unwrap_err();
+        assert!(!policy.should_retry(1, &parse_error.into()));
+    }
+}
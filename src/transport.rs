@@ -0,0 +1,185 @@
+//! Transports perform the actual HTTP requests of a [`HellApi`](crate::HellApi) client.
+//!
+//! The client ships with [`ReqwestTransport`] which talks to the network and
+//! [`InMemoryTransport`] which serves canned responses and is meant for tests and local
+//! stand-ins. Implement [`Transport`] to plug in anything else.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+
+use crate::prelude::Result;
+
+/// A GET request sent by a client.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// The full url of the request.
+    pub url: String,
+    /// The requested endpoint, e.g. `/api/v1/war`.
+    pub path: String,
+    /// The headers to send.
+    pub headers: HeaderMap,
+}
+
+impl Request {
+    /// Creates a request for `path` on the API at `base_url`.
+    pub fn new(base_url: &str, path: &str, headers: HeaderMap) -> Self {
+        Request {
+            url: base_url.to_owned() + path,
+            path: path.to_owned(),
+            headers,
+        }
+    }
+}
+
+/// A response returned by a [`Transport`].
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct Response {
+    /// The HTTP status code.
+    pub status: StatusCode,
+    /// The response headers.
+    pub headers: HeaderMap,
+    /// The raw response body.
+    pub body: Bytes,
+}
+
+impl Response {
+    /// Creates a response with the given `status` and `body` and no headers.
+    pub fn new(status: StatusCode, body: impl Into<Bytes>) -> Self {
+        Response {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Creates a `200 OK` response with the given `body`.
+    pub fn ok(body: impl Into<Bytes>) -> Self {
+        Self::new(StatusCode::OK, body)
+    }
+
+    /// Adds a header to the response.
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+}
+
+/// Performs requests on behalf of a client.
+#[async_trait]
+pub trait Transport: Debug + Send + Sync {
+    /// Sends the `request` and returns the response. Only failures to obtain a response are
+    /// errors, any status code is returned as a [`Response`].
+    async fn send(&self, request: Request) -> Result<Response>;
+}
+
+/// The default transport sending requests over the network using [`reqwest`].
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport using a default [`reqwest::Client`].
+    ///
+    /// The client sets no timeout itself, [`HellApi`](crate::HellApi) limits every request to
+    /// its [`timeout()`](crate::HellApiBuilder::timeout) instead.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let response = self
+            .client
+            .get(request.url)
+            .headers(request.headers)
+            .send()
+            .await?;
+
+        Ok(Response {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().await?,
+        })
+    }
+}
+
+/// A transport serving canned responses per path without touching the network.
+///
+/// Responses queued for the same path are served in order while the last one is repeated
+/// for all further requests. Requests to paths without a response are answered with
+/// `404 Not Found`.
+#[derive(Debug, Default)]
+pub struct InMemoryTransport {
+    responses: Mutex<HashMap<String, VecDeque<Response>>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl InMemoryTransport {
+    /// Creates a transport without any responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues `response` to be served for requests to `path`.
+    pub fn with_response(self, path: impl Into<String>, response: Response) -> Self {
+        self.push_response(path, response);
+        self
+    }
+
+    /// Queues `response` to be served for requests to `path`.
+    pub fn push_response(&self, path: impl Into<String>, response: Response) {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry(path.into())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Returns all requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Transport for InMemoryTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let response = match self.responses.lock().unwrap().get_mut(&request.path) {
+            Some(queue) if queue.len() > 1 => queue.pop_front(),
+            Some(queue) => queue.front().cloned(),
+            None => None,
+        };
+        self.requests.lock().unwrap().push(request);
+
+        Ok(response.unwrap_or_else(|| Response::new(StatusCode::NOT_FOUND, "")))
+    }
+}
+
+#[async_trait]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    async fn send(&self, request: Request) -> Result<Response> {
+        (**self).send(request).await
+    }
+}
@@ -1,17 +1,66 @@
-use helldivers2_rs::{models::raw::war::WarId, HellApi};
+mod common;
+
+use common::StandIn;
+use helldivers2_rs::models::{
+    common::{
+        assignment::{Objective, RewardType},
+        faction::Faction,
+    },
+    raw::{assignment::AssignmentFlags, war::WarId},
+};
 
 #[tokio::test]
 async fn v1_assignments_endpoint() {
-    let result = HellApi::assignments().await;
-    let inner = result.unwrap();
-    if let Some(first) = inner.first() {
-        let result = HellApi::assignment(first.id).await;
-        result.unwrap();
-    }
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+
+    let assignments = api.assignments().await.unwrap();
+    assert_eq!(assignments.len(), 1);
+    let first = assignments.first().unwrap();
+
+    let assignment = api.assignment(first.id).await.unwrap();
+    assert_eq!(assignment.tasks.len(), 3);
+    assert_eq!(assignment.reward.amount, 55);
+    assert_eq!(assignment.reward.reward_type, RewardType::Medals);
+
+    let progress = assignment.task_progress();
+    assert!(progress[0].is_complete());
+    assert_eq!((progress[2].progress, progress[2].target), (0, 500000000));
+    assert!((assignment.completion() - 1.0 / 3.0).abs() < 1e-9);
 }
 
 #[tokio::test]
 async fn raw_assignments_endpoint() {
-    let result = HellApi::assignments_raw(WarId::from(801)).await;
-    result.unwrap();
+    let server = StandIn::with_fixtures().await;
+    let assignments = server
+        .client()
+        .assignments_raw(WarId::from(801))
+        .await
+        .unwrap();
+
+    assert_eq!(assignments.len(), 1);
+    let setting = &assignments[0].setting;
+    assert_eq!(setting.reward._type, RewardType::Medals);
+    assert!(setting._type.is_major_order());
+    assert_eq!(setting.flags, AssignmentFlags::from_bits_retain(1));
+
+    let progress = assignments[0].task_progress();
+    assert_eq!(progress.len(), 3);
+    assert!(progress[0].is_complete());
+    assert_eq!(progress[1].fraction(), 0.0);
+
+    let tasks = &assignments[0].setting.tasks;
+    assert_eq!(tasks.len(), 3);
+    assert_eq!(
+        tasks[1].objective(),
+        Objective::LiberatePlanet { planet: 161 }
+    );
+    assert_eq!(
+        tasks[2].objective(),
+        Objective::Eradicate {
+            faction: Some(Faction::Terminids),
+            enemy: None,
+            kills: 500000000,
+        }
+    );
 }
@@ -0,0 +1,72 @@
+mod common;
+
+use std::time::Duration;
+
+use common::StandIn;
+use helldivers2_rs::{
+    cache::{CacheConfig, EndpointFamily},
+    models::raw::war::WarId,
+    HellApi,
+};
+
+#[tokio::test]
+async fn repeated_requests_are_cached() {
+    let server = StandIn::with_fixtures().await;
+    let api = HellApi::builder()
+        .base_url(server.url())
+        .cache(
+            CacheConfig::new(Duration::from_secs(60)).with_ttl(EndpointFamily::War, Duration::ZERO),
+        )
+        .build()
+        .unwrap();
+    let cache = api.cache().unwrap();
+
+    let first = api.planet(127).await.unwrap();
+    let second = api.planet(127).await.unwrap();
+    api.try_planet(127).await.unwrap();
+    assert_eq!(first.id, second.id);
+    assert_eq!(server.received().len(), 1);
+
+    api.war().await.unwrap();
+    api.war().await.unwrap();
+    assert_eq!(server.received().len(), 3);
+
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (2, 3, 1));
+}
+
+#[tokio::test]
+async fn invalidated_endpoints_are_requested_again() {
+    let server = StandIn::with_fixtures().await;
+    let api = HellApi::builder()
+        .base_url(server.url())
+        .cache(CacheConfig::default())
+        .build()
+        .unwrap();
+    let cache = api.cache().unwrap();
+    let war_id = WarId::from(801);
+
+    api.war_status(&war_id).await.unwrap();
+    api.planets().await.unwrap();
+    api.planet_events().await.unwrap();
+
+    cache.invalidate("/raw/api/WarSeason/801/Status");
+    cache.invalidate_family(EndpointFamily::Planets);
+    assert_eq!(cache.stats().entries, 0);
+
+    api.war_status(&war_id).await.unwrap();
+    api.planets().await.unwrap();
+    assert_eq!(server.received().len(), 5);
+}
+
+#[tokio::test]
+async fn caching_is_disabled_by_default() {
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+
+    api.campaigns().await.unwrap();
+    api.campaigns().await.unwrap();
+
+    assert!(api.cache().is_none());
+    assert_eq!(server.received().len(), 2);
+}
@@ -1,11 +1,18 @@
-use helldivers2_rs::HellApi;
+mod common;
+
+use common::StandIn;
 
 #[tokio::test]
 async fn v1_campaigns_endpoint() {
-    let result = HellApi::campaigns().await;
-    let inner = result.unwrap();
-    let first = inner.first().unwrap();
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+
+    let campaigns = api.campaigns().await.unwrap();
+    assert_eq!(campaigns.len(), 2);
+    assert!(campaigns[0].is_defense());
+    assert!(campaigns[1].is_liberation());
+    let last = campaigns.last().unwrap();
 
-    let result = HellApi::campaign(first.id).await;
-    result.unwrap();
+    let campaign = api.campaign(last.id).await.unwrap();
+    assert_eq!(&campaign, last);
 }
@@ -0,0 +1,67 @@
+mod common;
+
+use common::StandIn;
+use helldivers2_rs::{cassette::Cassette, HellApi};
+
+#[tokio::test]
+async fn replay_defense_ending_mid_poll() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/cassettes/defense-ends.json"
+    );
+    let api = HellApi::builder().replay_cassette(path).build().unwrap();
+
+    let before = api.planet(127).await.unwrap();
+    let after = api.planet(127).await.unwrap();
+
+    assert!(before.event.is_some());
+    assert!(after.event.is_none());
+}
+
+#[tokio::test]
+async fn record_and_replay() {
+    let server = StandIn::with_fixtures().await;
+    let path = std::env::temp_dir().join(format!("hd2-cassette-{}.json", std::process::id()));
+
+    let recorder = HellApi::builder()
+        .base_url(server.url())
+        .record_cassette(&path)
+        .build()
+        .unwrap();
+    let recorded = recorder.planets().await.unwrap();
+    recorder.planet(9999).await.unwrap_err();
+    drop(recorder);
+
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette.interactions.len(), 2);
+    assert_eq!(cassette.interactions[0].path, "/api/v1/planets");
+    assert_eq!(cassette.interactions[1].status, 404);
+
+    let replayer = HellApi::builder().replay_cassette(&path).build().unwrap();
+    let replayed = replayer.planets().await.unwrap();
+    replayer.planet(9999).await.unwrap_err();
+
+    assert_eq!(recorded, replayed);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn save_while_recording() {
+    let server = StandIn::with_fixtures().await;
+    let path = std::env::temp_dir().join(format!("hd2-saved-{}.json", std::process::id()));
+    let api = HellApi::builder()
+        .base_url(server.url())
+        .record_cassette(&path)
+        .build()
+        .unwrap();
+
+    api.war().await.unwrap();
+    assert!(!path.exists());
+    api.save_cassette().unwrap();
+    assert_eq!(Cassette::load(&path).unwrap().interactions.len(), 1);
+
+    api.planets().await.unwrap();
+    drop(api);
+    assert_eq!(Cassette::load(&path).unwrap().interactions.len(), 2);
+    std::fs::remove_file(path).unwrap();
+}
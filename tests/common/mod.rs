@@ -0,0 +1,223 @@
+//! A local stand-in for the Helldivers 2 API used by the integration tests.
+
+#![allow(dead_code)]
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use helldivers2_rs::HellApi;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// The requests per second allowed by [`StandIn::client()`].
+pub const GENEROUS_RATE_LIMIT: u32 = 1000;
+
+/// Maps every endpoint of the API to its recorded fixture in `tests/fixtures`.
+///
+/// The space station fixtures, including the station in `raw/war-status.json`, are written by
+/// hand after the shape of the API since no response with a station has been recorded yet. Their
+/// values, e.g. the statuses of tactical actions, are made up and should be replaced by recorded
+/// ones.
+pub const FIXTURES: &[(&str, &str)] = &[
+    ("/api/v1/war", "v1/war.json"),
+    ("/api/v1/assignments", "v1/assignments.json"),
+    ("/api/v1/assignments/3690749963", "v1/assignment.json"),
+    ("/api/v1/campaigns", "v1/campaigns.json"),
+    ("/api/v1/campaigns/49951", "v1/campaign.json"),
+    ("/api/v1/dispatches", "v1/dispatches.json"),
+    ("/api/v1/dispatches/2301", "v1/dispatch.json"),
+    ("/api/v1/planets", "v1/planets.json"),
+    ("/api/v1/planets/127", "v1/planet.json"),
+    ("/api/v1/planet-events", "v1/planet-events.json"),
+    ("/api/v1/space-stations", "v1/space-stations.json"),
+    ("/api/v1/space-stations/749875195", "v1/space-station.json"),
+    ("/api/v1/steam", "v1/steam.json"),
+    ("/api/v1/steam/6098733004414627063", "v1/steam-item.json"),
+    ("/raw/api/WarSeason/current/WarID", "raw/war-id.json"),
+    ("/raw/api/WarSeason/801/Status", "raw/war-status.json"),
+    ("/raw/api/WarSeason/801/WarInfo", "raw/war-info.json"),
+    ("/raw/api/Stats/war/801/summary", "raw/war-summary.json"),
+    ("/raw/api/NewsFeed/801", "raw/news-feed.json"),
+    ("/raw/api/v2/Assignment/War/801", "raw/assignments.json"),
+    (
+        "/raw/api/v2/SpaceStation/War/801/749875195",
+        "raw/space-station.json",
+    ),
+];
+
+/// Reads the recorded fixture at `tests/fixtures/{name}`.
+pub fn fixture(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    std::fs::read_to_string(path).unwrap()
+}
+
+/// A canned HTTP response served by the [`StandIn`] server.
+#[derive(Debug, Clone)]
+pub struct Canned {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Canned {
+    /// A `200 OK` response with a JSON `body`. Reports no rate limit, see
+    /// [`header()`](Canned::header) to add one.
+    pub fn ok(body: impl Into<String>) -> Self {
+        Canned {
+            status: 200,
+            headers: vec![("Content-Type".into(), "application/json".into())],
+            body: body.into(),
+        }
+    }
+
+    /// A response with the given `status` and an empty body.
+    pub fn status(status: u16) -> Self {
+        Canned {
+            status,
+            headers: vec![],
+            body: String::new(),
+        }
+    }
+
+    /// Adds a header to the response.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// A request received by the [`StandIn`] server.
+#[derive(Debug, Clone)]
+pub struct Received {
+    pub path: String,
+    /// Header names are lowercase.
+    pub headers: HashMap<String, String>,
+}
+
+#[derive(Default)]
+struct State {
+    routes: HashMap<String, VecDeque<Canned>>,
+    received: Vec<Received>,
+}
+
+/// A minimal HTTP/1.1 server answering with canned responses per path. Responses queued for
+/// the same path are served in order, the last one is repeated. Unknown paths answer `404`.
+pub struct StandIn {
+    url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl StandIn {
+    /// Starts the server on a random local port.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let shared = state.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, shared.clone()));
+            }
+        });
+
+        StandIn { url, state }
+    }
+
+    /// Starts the server replaying all recorded [`FIXTURES`].
+    pub async fn with_fixtures() -> Self {
+        let server = Self::start().await;
+        for (path, name) in FIXTURES {
+            server.route(path, Canned::ok(fixture(name)));
+        }
+        server
+    }
+
+    /// Queues `response` for requests to `path`.
+    pub fn route(&self, path: &str, response: Canned) -> &Self {
+        let mut state = self.state.lock().unwrap();
+        state
+            .routes
+            .entry(path.to_owned())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// The base url of the server.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A client pointed at this server. Its rate limit is generous enough to never wait, so
+    /// rate limiting is only exercised by `tests/rate_limit.rs`.
+    pub fn client(&self) -> HellApi {
+        HellApi::builder()
+            .base_url(self.url())
+            .rate_limit(GENEROUS_RATE_LIMIT, Duration::from_secs(1))
+            .build()
+            .unwrap()
+    }
+
+    /// All requests received so far.
+    pub fn received(&self) -> Vec<Received> {
+        self.state.lock().unwrap().received.clone()
+    }
+}
+
+async fn serve(mut stream: TcpStream, state: Arc<Mutex<State>>) {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+        }
+    }
+
+    let head = String::from_utf8_lossy(&buffer);
+    let mut lines = head.split("\r\n");
+    let path = lines
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .unwrap_or("/")
+        .to_owned();
+    let headers = lines
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_owned()))
+        .collect();
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.received.push(Received {
+            path: path.clone(),
+            headers,
+        });
+        match state.routes.get_mut(&path) {
+            Some(queue) if queue.len() > 1 => queue.pop_front().unwrap(),
+            Some(queue) if !queue.is_empty() => queue[0].clone(),
+            _ => Canned::status(404),
+        }
+    };
+
+    let mut raw = format!("HTTP/1.1 {} Stand-In\r\n", response.status);
+    for (name, value) in &response.headers {
+        raw += &format!("{name}: {value}\r\n");
+    }
+    raw += &format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.body.len(),
+        response.body
+    );
+    let _ = stream.write_all(raw.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
@@ -1,27 +1,40 @@
-use helldivers2_rs::{models::raw::war::WarId, HellApi};
+mod common;
+
+use common::StandIn;
+use helldivers2_rs::models::{raw::war::WarId, v1::dispatch::Message};
 
 #[tokio::test]
 async fn v1_dispatches_endpoint() {
-    let result = HellApi::dispatches().await;
-    let inner = result.unwrap();
-    let first = inner.first().unwrap();
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+
+    let dispatches = api.dispatches().await.unwrap();
+    assert_eq!(dispatches.len(), 2);
+    assert!(matches!(dispatches[1].message, Message::Localized(_)));
+    let first = dispatches.first().unwrap();
 
-    let result = HellApi::dispatch(first.id).await;
-    result.unwrap();
+    let dispatch = api.dispatch(first.id).await.unwrap();
+    assert_eq!(&dispatch, first);
 }
 
 #[tokio::test]
 async fn v1_steam_endpoint() {
-    let result = HellApi::steam_newsfeed().await;
-    let inner = result.unwrap();
-    let first = inner.first().unwrap();
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
 
-    let result = HellApi::steam_newsitem(&first.id).await;
-    result.unwrap();
+    let news = api.steam_newsfeed().await.unwrap();
+    assert_eq!(news.len(), 2);
+    let first = news.first().unwrap();
+
+    let item = api.steam_newsitem(&first.id).await.unwrap();
+    assert_eq!(&item, first);
 }
 
 #[tokio::test]
 async fn raw_news_feed_endpoint() {
-    let result = HellApi::news_feed(WarId::from(801)).await;
-    result.unwrap();
+    let server = StandIn::with_fixtures().await;
+    let feed = server.client().news_feed(WarId::from(801)).await.unwrap();
+
+    assert_eq!(feed.len(), 2);
+    assert_eq!(feed[0].id, 2300);
 }
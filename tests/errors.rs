@@ -0,0 +1,131 @@
+mod common;
+
+use std::time::Instant;
+
+use common::{fixture, Canned, StandIn};
+use helldivers2_rs::{models::raw::war::WarId, prelude::Error, retry::RetryPolicy, HellApi};
+
+#[tokio::test]
+async fn not_found() {
+    let server = StandIn::with_fixtures().await;
+    let result = server.client().planet(9999).await;
+
+    match result {
+        Err(error @ Error::NotFound { .. }) => {
+            assert_eq!(error.endpoint(), Some("/api/v1/planets/9999"));
+            assert!(!error.is_retryable());
+        }
+        other => panic!("expected NotFound error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn too_many_requests_waits_for_retry_after() {
+    let server = StandIn::start().await;
+    let endpoint = "/raw/api/WarSeason/801/Status";
+    server
+        .route(endpoint, Canned::status(429).header("Retry-After", "1"))
+        .route(endpoint, Canned::ok(fixture("raw/war-status.json")));
+    let api = server.client();
+
+    let start = Instant::now();
+    let status = api.war_status(&WarId::from(801)).await.unwrap();
+
+    assert_eq!(status.war_id, 801);
+    assert_eq!(server.received().len(), 2);
+    assert!(start.elapsed().as_secs() >= 1);
+}
+
+#[tokio::test]
+async fn server_error() {
+    let server = StandIn::start().await;
+    let mut response = Canned::status(500);
+    response.body = String::from("Internal Server Error");
+    server.route("/api/v1/war", response);
+
+    let result = server.client().war().await;
+
+    match result {
+        Err(Error::HttpStatus {
+            endpoint,
+            status,
+            body_snippet,
+        }) => {
+            assert_eq!(endpoint, "/api/v1/war");
+            assert_eq!(status, 500);
+            assert_eq!(body_snippet, "Internal Server Error");
+        }
+        other => panic!("expected HttpStatus error, got {other:?}"),
+    }
+    assert_eq!(server.received().len(), 1);
+}
+
+#[tokio::test]
+async fn service_unavailable() {
+    let server = StandIn::start().await;
+    server.route("/api/v1/war", Canned::status(503));
+    let api = HellApi::builder()
+        .base_url(server.url())
+        .retry_policy(RetryPolicy::never())
+        .build()
+        .unwrap();
+
+    let error = api.war().await.unwrap_err();
+
+    assert!(matches!(error, Error::ServiceUnavailable { .. }));
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn gateway_timeout_is_retried() {
+    let server = StandIn::start().await;
+    server
+        .route("/api/v1/war", Canned::status(504))
+        .route("/api/v1/war", Canned::ok(fixture("v1/war.json")));
+
+    server.client().war().await.unwrap();
+
+    assert_eq!(server.received().len(), 2);
+}
+
+#[tokio::test]
+async fn invalid_payload() {
+    let server = StandIn::start().await;
+    server.route("/api/v1/war", Canned::ok(r#"{"started": null}"#));
+
+    let result = server.client().war().await;
+
+    assert!(matches!(result, Err(Error::ParseError { .. })));
+}
+
+#[tokio::test]
+async fn parse_error_reports_path_and_payload() {
+    let server = StandIn::start().await;
+    let mut planets: serde_json::Value = serde_json::from_str(&fixture("v1/planets.json")).unwrap();
+    planets[1]["event"]["jointOperationIds"][0] = serde_json::Value::Null;
+    let payload = planets.to_string();
+    server.route("/api/v1/planets", Canned::ok(payload.clone()));
+    let api = HellApi::builder()
+        .base_url(server.url())
+        .dump_payloads(true)
+        .build()
+        .unwrap();
+
+    let error = api.planets().await.unwrap_err();
+
+    assert_eq!(error.endpoint(), Some("/api/v1/planets"));
+    assert!(error
+        .to_string()
+        .contains("from /api/v1/planets at `[1].event.jointOperationIds[0]`"));
+    match error {
+        Error::ParseError {
+            path,
+            payload: dump,
+            ..
+        } => {
+            assert_eq!(path, "[1].event.jointOperationIds[0]");
+            assert_eq!(dump, Some(payload));
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::{Canned, StandIn};
+use helldivers2_rs::{client::DEFAULT_USER_AGENT, HellApi};
+
+#[tokio::test]
+async fn default_identification_headers() {
+    let server = StandIn::start().await;
+    server.route(
+        "/raw/api/WarSeason/current/WarID",
+        Canned::ok(r#"{"id":801}"#),
+    );
+
+    server.client().war_id().await.unwrap();
+
+    let received = server.received();
+    let headers = &received.last().unwrap().headers;
+    assert_eq!(headers["user-agent"], DEFAULT_USER_AGENT);
+    assert_eq!(headers["x-super-client"], "helldivers2-rs");
+    assert!(!headers.contains_key("x-super-contact"));
+    assert!(!headers.contains_key("accept-language"));
+}
+
+#[tokio::test]
+async fn configured_identification_headers() {
+    let server = StandIn::start().await;
+    server.route(
+        "/raw/api/WarSeason/current/WarID",
+        Canned::ok(r#"{"id":801}"#),
+    );
+
+    let api = HellApi::builder()
+        .base_url(server.url())
+        .user_agent("democracy-bot/1.0")
+        .client_name("democracy-bot")
+        .contact("admin@super-earth.example")
+        .language("de-DE")
+        .build()
+        .unwrap();
+    api.war_id().await.unwrap();
+
+    let received = server.received();
+    let headers = &received.last().unwrap().headers;
+    assert_eq!(headers["user-agent"], "democracy-bot/1.0");
+    assert_eq!(headers["x-super-client"], "democracy-bot");
+    assert_eq!(headers["x-super-contact"], "admin@super-earth.example");
+    assert_eq!(headers["accept-language"], "de-DE");
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::{fixture, Canned, StandIn};
+use helldivers2_rs::{lenient::lenient, models::raw::war::WarId, prelude::Error};
+use serde_json::{json, Value};
+
+const WAR_STATUS: &str = "/raw/api/WarSeason/801/Status";
+
+/// The recorded war status with a second planet event whose campaign id is missing.
+fn drifted_war_status() -> String {
+    let mut status: Value = serde_json::from_str(&fixture("raw/war-status.json")).unwrap();
+    let mut event = status["planetEvents"][0].clone();
+    event["campaignId"] = Value::Null;
+    status["planetEvents"].as_array_mut().unwrap().push(event);
+    status["planetStatus"][0]["newField"] = json!({"enabled": true});
+    status.to_string()
+}
+
+#[tokio::test]
+async fn lenient_requests_skip_invalid_elements() {
+    let server = StandIn::start().await;
+    server.route(WAR_STATUS, Canned::ok(drifted_war_status()));
+    let api = server.client();
+
+    let status = lenient(api.war_status(&WarId::from(801))).await.unwrap();
+
+    assert_eq!(status.value.planet_events.len(), 1);
+    assert_eq!(status.warnings.len(), 1);
+    assert_eq!(status.warnings[0].endpoint, WAR_STATUS);
+    assert_eq!(status.warnings[0].path, "planetEvents[1].campaignId");
+    #[cfg(feature = "extra")]
+    assert_eq!(
+        status.value.planet_status[0].extra["newField"],
+        json!({"enabled": true})
+    );
+}
+
+#[tokio::test]
+async fn strict_requests_fail() {
+    let server = StandIn::start().await;
+    server.route(WAR_STATUS, Canned::ok(drifted_war_status()));
+
+    let result = server.client().war_status(&WarId::from(801)).await;
+
+    match result {
+        Err(Error::ParseError { path, .. }) => assert_eq!(path, "planetEvents[1].campaignId"),
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn valid_responses_have_no_warnings() {
+    let server = StandIn::with_fixtures().await;
+
+    let planets = lenient(server.client().planets()).await.unwrap();
+
+    assert_eq!(planets.value.len(), 3);
+    assert!(planets.warnings.is_empty());
+}
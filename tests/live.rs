@@ -0,0 +1,149 @@
+//! Runs the API surface against the live API at `api.helldivers2.dev`.
+//!
+//! These tests require network access and are ignored by default. Run them using
+//! `cargo test --test live -- --ignored`.
+
+use helldivers2_rs::{models::raw::war::WarId, HellApi};
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_war_endpoint() {
+    let api = HellApi::new();
+    api.war().await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn raw_war_id_endpoint() {
+    let api = HellApi::new();
+    api.war_id().await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn raw_war_status_endpoint() {
+    let api = HellApi::new();
+    api.war_status(&WarId::from(801)).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn raw_war_info_endpoint() {
+    let api = HellApi::new();
+    api.war_info(&WarId::from(801)).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn raw_war_summary_endpoint() {
+    let api = HellApi::new();
+    api.war_summary(&WarId::from(801)).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_planets_endpoint() {
+    let api = HellApi::new();
+    let result = api.planets().await;
+    let inner = result.unwrap();
+    let first = inner.first().unwrap();
+
+    let result = api.planet(first.id).await;
+    result.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_planet_events_endpoint() {
+    let api = HellApi::new();
+    let result = api.planet_events().await;
+    result.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_dispatches_endpoint() {
+    let api = HellApi::new();
+    let result = api.dispatches().await;
+    let inner = result.unwrap();
+    let first = inner.first().unwrap();
+
+    let result = api.dispatch(first.id).await;
+    result.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_steam_endpoint() {
+    let api = HellApi::new();
+    let result = api.steam_newsfeed().await;
+    let inner = result.unwrap();
+    let first = inner.first().unwrap();
+
+    let result = api.steam_newsitem(&first.id).await;
+    result.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn raw_news_feed_endpoint() {
+    let api = HellApi::new();
+    let result = api.news_feed(WarId::from(801)).await;
+    result.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_assignments_endpoint() {
+    let api = HellApi::new();
+    let result = api.assignments().await;
+    let inner = result.unwrap();
+    if let Some(first) = inner.first() {
+        let result = api.assignment(first.id).await;
+        result.unwrap();
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn raw_assignments_endpoint() {
+    let api = HellApi::new();
+    let result = api.assignments_raw(WarId::from(801)).await;
+    result.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_campaigns_endpoint() {
+    let api = HellApi::new();
+    let result = api.campaigns().await;
+    let inner = result.unwrap();
+    let first = inner.first().unwrap();
+
+    let result = api.campaign(first.id).await;
+    result.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn v1_space_stations_endpoint() {
+    let api = HellApi::new();
+    let result = api.space_stations().await;
+    let inner = result.unwrap();
+    if let Some(first) = inner.first() {
+        let result = api.space_station(first.id32).await;
+        result.unwrap();
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn raw_space_station_endpoint() {
+    let api = HellApi::new();
+    let war_id = api.war_id().await.unwrap();
+    let status = api.war_status(&war_id).await.unwrap();
+    if let Some(first) = status.space_stations.first() {
+        let result = api.space_station_raw(&war_id, first.id32).await;
+        result.unwrap();
+    }
+}
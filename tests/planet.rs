@@ -1,17 +1,29 @@
-use helldivers2_rs::HellApi;
+mod common;
+
+use common::StandIn;
+use helldivers2_rs::models::common::faction::Faction;
 
 #[tokio::test]
 async fn v1_planets_endpoint() {
-    let result = HellApi::planets().await;
-    let inner = result.unwrap();
-    let first = inner.first().unwrap();
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+
+    let planets = api.planets().await.unwrap();
+    assert_eq!(planets.len(), 3);
+    let estanu = &planets[1];
 
-    let result = HellApi::planet(first.id).await;
-    result.unwrap();
+    let planet = api.planet(estanu.id).await.unwrap();
+    assert_eq!(&planet, estanu);
+    assert_eq!(planet.current_owner, Faction::Humans);
+    assert_eq!(planet.event.unwrap().faction, Faction::Terminids);
+    assert_eq!(planets[2].current_owner, Faction::Automaton);
 }
 
 #[tokio::test]
 async fn v1_planet_events_endpoint() {
-    let result = HellApi::planet_events().await;
-    result.unwrap();
+    let server = StandIn::with_fixtures().await;
+    let planets = server.client().planet_events().await.unwrap();
+
+    assert_eq!(planets.len(), 1);
+    assert_eq!(planets[0].event.as_ref().unwrap().id, 5317);
 }
@@ -0,0 +1,85 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{Canned, StandIn};
+use helldivers2_rs::{models::raw::war::WarId, prelude::Error, HellApi};
+
+#[tokio::test]
+async fn try_endpoints_fail_fast() {
+    let server = StandIn::with_fixtures().await;
+    let api = HellApi::builder()
+        .base_url(server.url())
+        .rate_limit(1, Duration::from_secs(1))
+        .build()
+        .unwrap();
+
+    api.try_war_status(&WarId::from(801)).await.unwrap();
+    let result = api.try_planets().await;
+
+    match result {
+        Err(Error::RateLimitReached(wait_for)) => assert!(wait_for <= Duration::from_secs(1)),
+        other => panic!("expected RateLimitReached error, got {other:?}"),
+    }
+    assert_eq!(server.received().len(), 1);
+
+    api.wait_until_ready().await;
+    assert_eq!(api.try_planets().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn try_endpoints_cover_the_api() {
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+    let war_id = WarId::from(801);
+
+    api.try_war().await.unwrap();
+    api.try_assignments().await.unwrap();
+    api.try_assignment(3690749963).await.unwrap();
+    api.try_campaigns().await.unwrap();
+    api.try_campaign(49951).await.unwrap();
+    api.try_dispatches().await.unwrap();
+    api.try_dispatch(2301).await.unwrap();
+    api.try_steam_newsfeed().await.unwrap();
+    api.try_steam_newsitem(&String::from("6098733004414627063"))
+        .await
+        .unwrap();
+    api.try_planets().await.unwrap();
+    api.try_planet(127).await.unwrap();
+    api.try_planet_events().await.unwrap();
+    api.try_space_stations().await.unwrap();
+    api.try_space_station(749875195).await.unwrap();
+    api.try_war_id().await.unwrap();
+    api.try_war_status(&war_id).await.unwrap();
+    api.try_global_events(&war_id).await.unwrap();
+    api.try_war_info(&war_id).await.unwrap();
+    api.try_war_summary(&war_id).await.unwrap();
+    api.try_news_feed(WarId::from(801)).await.unwrap();
+    api.try_assignments_raw(WarId::from(801)).await.unwrap();
+    api.try_space_station_raw(&war_id, 749875195).await.unwrap();
+
+    assert_eq!(server.received().len(), 22);
+}
+
+#[tokio::test]
+async fn reported_rate_limit_is_respected() {
+    let server = StandIn::start().await;
+    server.route(
+        "/raw/api/WarSeason/current/WarID",
+        Canned::ok(r#"{"id":801}"#)
+            .header("X-Ratelimit-Limit", "5")
+            .header("X-Ratelimit-Remaining", "0")
+            .header("X-Ratelimit-Reset", "10"),
+    );
+    let api = server.client();
+
+    api.try_war_id().await.unwrap();
+    let result = api.try_war_id().await;
+
+    match result {
+        Err(Error::RateLimitReached(wait_for)) => assert!(wait_for > Duration::from_secs(9)),
+        other => panic!("expected RateLimitReached error, got {other:?}"),
+    }
+    assert_eq!(api.rate_limit().limit, 5);
+    assert_eq!(server.received().len(), 1);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::{fixture, StandIn};
+use helldivers2_rs::models::raw::war::WarId;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Collects the paths below `path` at which `actual` differs from `expected`. Integers are
+/// compared exactly, so ids differing in their last digits are found. Only two floats are
+/// compared by their `f32` value.
+fn differences(path: &str, actual: &Value, expected: &Value, found: &mut Vec<String>) {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => {
+            for key in actual
+                .keys()
+                .chain(expected.keys().filter(|k| !actual.contains_key(*k)))
+            {
+                let path = format!("{path}.{key}");
+                match (actual.get(key), expected.get(key)) {
+                    (Some(a), Some(e)) => differences(&path, a, e, found),
+                    (a, e) => found.push(format!("{path}: {a:?} != {e:?}")),
+                }
+            }
+        }
+        (Value::Array(actual), Value::Array(expected)) if actual.len() == expected.len() => {
+            for (index, (a, e)) in actual.iter().zip(expected).enumerate() {
+                differences(&format!("{path}[{index}]"), a, e, found);
+            }
+        }
+        // `f32` fields keep only the precision they are stored with
+        (Value::Number(a), Value::Number(e)) if a.is_f64() && e.is_f64() => {
+            if a.as_f64().map(|a| a as f32) != e.as_f64().map(|e| e as f32) {
+                found.push(format!("{path}: {a} != {e}"));
+            }
+        }
+        // the API omits the `.0` of whole floats
+        (Value::Number(a), Value::Number(e)) if a.is_f64() || e.is_f64() => {
+            if a.as_f64() != e.as_f64() {
+                found.push(format!("{path}: {a} != {e}"));
+            }
+        }
+        (a, e) if a == e => {}
+        (a, e) => found.push(format!("{path}: {a} != {e}")),
+    }
+}
+
+/// Asserts that `value` serializes to the JSON of the fixture it was parsed from.
+fn assert_serializes_to_fixture<T: Serialize>(value: T, name: &str) {
+    let expected: Value = serde_json::from_str(&fixture(name)).unwrap();
+    let actual = serde_json::to_value(&value).unwrap();
+
+    let mut found = Vec::new();
+    differences("", &actual, &expected, &mut found);
+    assert!(found.is_empty(), "{name} differs:\n{}", found.join("\n"));
+}
+
+#[tokio::test]
+async fn v1_models_serialize_to_their_fixtures() {
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+
+    assert_serializes_to_fixture(api.war().await.unwrap(), "v1/war.json");
+    assert_serializes_to_fixture(api.assignments().await.unwrap(), "v1/assignments.json");
+    assert_serializes_to_fixture(api.campaigns().await.unwrap(), "v1/campaigns.json");
+    assert_serializes_to_fixture(api.dispatches().await.unwrap(), "v1/dispatches.json");
+    assert_serializes_to_fixture(api.steam_newsfeed().await.unwrap(), "v1/steam.json");
+    assert_serializes_to_fixture(api.planets().await.unwrap(), "v1/planets.json");
+    assert_serializes_to_fixture(api.planet_events().await.unwrap(), "v1/planet-events.json");
+    assert_serializes_to_fixture(
+        api.space_stations().await.unwrap(),
+        "v1/space-stations.json",
+    );
+}
+
+#[tokio::test]
+async fn raw_models_serialize_to_their_fixtures() {
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+    let war_id = WarId::from(801);
+
+    assert_serializes_to_fixture(api.war_id().await.unwrap(), "raw/war-id.json");
+    assert_serializes_to_fixture(
+        api.war_status(&war_id).await.unwrap(),
+        "raw/war-status.json",
+    );
+    assert_serializes_to_fixture(api.war_info(&war_id).await.unwrap(), "raw/war-info.json");
+    assert_serializes_to_fixture(
+        api.war_summary(&war_id).await.unwrap(),
+        "raw/war-summary.json",
+    );
+    assert_serializes_to_fixture(
+        api.news_feed(WarId::from(801)).await.unwrap(),
+        "raw/news-feed.json",
+    );
+    assert_serializes_to_fixture(
+        api.space_station_raw(&war_id, 749875195).await.unwrap(),
+        "raw/space-station.json",
+    );
+    assert_serializes_to_fixture(
+        api.assignments_raw(WarId::from(801)).await.unwrap(),
+        "raw/assignments.json",
+    );
+}
+
+#[tokio::test]
+async fn raw_timestamps_keep_their_wire_format() {
+    let server = StandIn::with_fixtures().await;
+    let status = server.client().war_status(&WarId::from(801)).await.unwrap();
+
+    let json = serde_json::to_value(&status).unwrap();
+    let event: &Value = &json["planetEvents"][0];
+
+    assert_eq!(event["startTime"], 1720282232);
+    assert_eq!(event["expireTime"], 1720368632);
+    assert_eq!(event["race"], 2);
+    assert_eq!(json["planetStatus"][2]["owner"], 3);
+}
+
+#[tokio::test]
+async fn war_status_stations_omit_tactical_actions() {
+    let server = StandIn::with_fixtures().await;
+    let status = server.client().war_status(&WarId::from(801)).await.unwrap();
+
+    let json = serde_json::to_value(&status).unwrap();
+
+    assert_eq!(json["spaceStations"][0]["id32"], 749875195);
+    assert!(json["spaceStations"][0].get("tacticalActions").is_none());
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::StandIn;
+use helldivers2_rs::models::raw::war::WarId;
+
+#[tokio::test]
+async fn v1_space_stations_endpoint() {
+    let server = StandIn::with_fixtures().await;
+    let api = server.client();
+
+    let stations = api.space_stations().await.unwrap();
+    assert_eq!(stations.len(), 1);
+    let first = stations.first().unwrap();
+
+    let station = api.space_station(first.id32).await.unwrap();
+    assert_eq!(&station, first);
+    assert_eq!(station.planet.id, 127);
+
+    let actions = &station.tactical_actions;
+    assert_eq!(actions[0].status, 1);
+    assert_eq!(actions[0].costs[0].fraction(), 0.4);
+    assert_eq!(actions[1].status, 2);
+    assert!(actions[1].costs[0].is_complete());
+}
+
+#[tokio::test]
+async fn raw_space_station_endpoint() {
+    let server = StandIn::with_fixtures().await;
+    let station = server
+        .client()
+        .space_station_raw(&WarId::from(801), 749875195)
+        .await
+        .unwrap();
+
+    assert_eq!(station.planet_index, 127);
+    assert_eq!(station.current_election_end_war_time, 14400000);
+
+    let action = &station.tactical_actions[1];
+    assert_eq!(action.status, 2);
+    assert_eq!(action.active_effect_ids, [1234]);
+    assert_eq!(action.cost[0].target_value, 100.0);
+}
@@ -0,0 +1,18 @@
+mod common;
+
+use common::StandIn;
+use helldivers2_rs::models::raw::war::WarId;
+
+#[tokio::test]
+async fn raw_war_summary_endpoint() {
+    let server = StandIn::with_fixtures().await;
+    let summary = server
+        .client()
+        .war_summary(&WarId::from(801))
+        .await
+        .unwrap();
+
+    assert_eq!(summary.galaxy_stats.missions_won, 180312441);
+    assert_eq!(summary.planet_stats.len(), 3);
+    assert_eq!(summary.planet_stats[2].planet_index, 161);
+}
@@ -1,26 +1,109 @@
-use helldivers2_rs::{models::raw::war::WarId, HellApi};
+mod common;
+
+use common::StandIn;
+use helldivers2_rs::models::{
+    common::{campaign::CampaignType, faction::Faction},
+    raw::war::{WarId, WarStatus},
+};
 
 #[tokio::test]
 async fn v1_war_endpoint() {
-    HellApi::war().await.unwrap();
+    let server = StandIn::with_fixtures().await;
+    let war = server.client().war().await.unwrap();
+
+    assert_eq!(war.client_version, "0.3.0");
+    assert_eq!(
+        war.factions,
+        [
+            Faction::Humans,
+            Faction::Terminids,
+            Faction::Automaton,
+            Faction::Illuminate
+        ]
+    );
+    assert_eq!(war.statistics.player_count, 94513);
 }
 
 #[tokio::test]
 async fn raw_war_id_endpoint() {
-    HellApi::war_id().await.unwrap();
+    let server = StandIn::with_fixtures().await;
+    let war_id = server.client().war_id().await.unwrap();
+
+    assert_eq!(war_id.id, 801);
 }
 
 #[tokio::test]
 async fn raw_war_status_endpoint() {
-    HellApi::war_status(&WarId::from(801)).await.unwrap();
+    let server = StandIn::with_fixtures().await;
+    let status = server.client().war_status(&WarId::from(801)).await.unwrap();
+
+    assert_eq!(status.war_id, 801);
+    assert_eq!(status.planet_status.len(), 3);
+    assert_eq!(status.campaigns.len(), 2);
+    assert_eq!(status.joint_operations[0].planet_index, 127);
+    assert_eq!(status.planet_events[0].campaign_id, 49942);
+    assert_eq!(status.planet_events[0].race, Faction::Terminids);
+    assert!(status.planet_events[0].event_type.is_defense());
+    assert_eq!(status.campaigns[0].campaign_type, CampaignType::Liberation);
+    assert!(status.campaigns[0].is_defense(&status.planet_events));
+    assert!(status.campaigns[1].is_liberation(&status.planet_events));
+    assert_eq!(status.planet_active_effects[0].galactic_effect_id, 1190);
+    assert_eq!(status.global_events[0].event_id, 1251416);
+    assert_eq!(status.global_events[0].race, Faction::Terminids);
+    assert_eq!(status.global_events[0].planet_indices, [161]);
+    assert_eq!(status.space_stations[0].planet_index, 127);
+    assert_eq!(status.space_stations[0].current_election_id, "5014599");
+    assert_eq!(status.layout_version, 48);
 }
 
 #[tokio::test]
-async fn raw_war_info_endpoint() {
-    HellApi::war_info(&WarId::from(801)).await.unwrap();
+async fn global_events() {
+    let server = StandIn::with_fixtures().await;
+    let events = server
+        .client()
+        .global_events(&WarId::from(801))
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].title, "BRIEFING");
+    assert_eq!(events[0].effect_ids, [1190]);
+    assert!(events[0].affects(161));
+    assert_eq!(events[0].assignment(), None);
+}
+
+#[test]
+fn raw_war_status_without_recent_fields() {
+    let mut json: serde_json::Value =
+        serde_json::from_str(&common::fixture("raw/war-status.json")).unwrap();
+    for field in [
+        "planetActiveEffects",
+        "activeElectionPolicyEffects",
+        "globalEvents",
+        "communityTargets",
+        "superEarthWarResults",
+        "spaceStations",
+        "layoutVersion",
+    ] {
+        json.as_object_mut().unwrap().remove(field);
+    }
+
+    let status: WarStatus = serde_json::from_value(json).unwrap();
+
+    assert!(status.global_events.is_empty());
+    assert!(status.space_stations.is_empty());
+    assert_eq!(status.layout_version, 0);
+    assert_eq!(status.planet_status[2].owner, Faction::Automaton);
 }
 
 #[tokio::test]
-async fn raw_war_summary_endpoint() {
-    HellApi::war_summary(&WarId::from(801)).await.unwrap();
+async fn raw_war_info_endpoint() {
+    let server = StandIn::with_fixtures().await;
+    let info = server.client().war_info(&WarId::from(801)).await.unwrap();
+
+    assert_eq!(info.war_id, 801);
+    assert_eq!(info.planet_infos.len(), 3);
+    assert_eq!(info.home_worlds[0].planet_indices, vec![0]);
+    assert_eq!(info.home_worlds[0].race, Faction::Humans);
+    assert_eq!(info.planet_infos[1].initial_owner, Faction::Humans);
 }